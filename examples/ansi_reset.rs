@@ -1,7 +1,6 @@
 use bitmap_writer::{Writer, Bitmap, Frame, Style};
 
 use std::{thread, time};
-use std::io::BufWriter;
 
 fn main() {
     let bitmap_0 = Bitmap::new(16, 8, &[
@@ -33,9 +32,9 @@ fn main() {
     
     let mut cnt: usize = 0;
     loop {
-        w.frame(if cnt % 2 == 0 { Frame::UnicodeFrame } else { Frame::UnicodeDoubleUFrame } );
+        w.frame(if cnt.is_multiple_of(2) { Frame::UnicodeFrame } else { Frame::UnicodeDoubleUFrame } );
         
-        w.print(if cnt % 2 == 0 { &bitmap_0 } else { &bitmap_1 });        
+        w.print(if cnt.is_multiple_of(2) { &bitmap_0 } else { &bitmap_1 });        
 
         cnt += 1;
 
@@ -1,18 +1,18 @@
-/// Demonstrates the practical use of displaying a digital clock with a custom
-/// bitmap font.
-/// 
-/// Example output:
-/// 
-/// ```
-/// ┏━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
-/// ┃  ▄███  ██████▄   ▄▄▄      ▄███ ███████   ▄▄▄   ███████ ██████▄ ┃
-/// ┃  ▀███  ▀▀▀███▀   ███    ▄██▀██ ▀▀▀▀███   ███   ▀▀▀▀███ ▀▀▀███▀ ┃
-/// ┃   ███   ▄██▀     ▄▄▄   ███▄▄██▄   ▀▀█▄   ▄▄▄      ▀▀█▄  ▄██▀   ┃
-/// ┃   ▀▀▀  ▀▀▀▀      ▀▀▀       ▀▀▀ ▀▀▀▀▀▀    ▀▀▀   ▀▀▀▀▀▀  ▀▀▀▀    ┃
-/// ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
-/// ```
+//! Demonstrates the practical use of displaying a digital clock with a custom
+//! bitmap font.
+//! 
+//! Example output:
+//! 
+//! ```
+//! ┏━ clock ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┓
+//! ┃  ▄███  ██████▄   ▄▄▄      ▄███ ███████   ▄▄▄   ███████ ██████▄ ┃
+//! ┃  ▀███  ▀▀▀███▀   ███    ▄██▀██ ▀▀▀▀███   ███   ▀▀▀▀███ ▀▀▀███▀ ┃
+//! ┃   ███   ▄██▀     ▄▄▄   ███▄▄██▄   ▀▀█▄   ▄▄▄      ▀▀█▄  ▄██▀   ┃
+//! ┃   ▀▀▀  ▀▀▀▀      ▀▀▀       ▀▀▀ ▀▀▀▀▀▀    ▀▀▀   ▀▀▀▀▀▀  ▀▀▀▀    ┃
+//! ┗━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━┛
+//! ```
 
-use bitmap_writer::{Writer, Bitmap, Frame, Style};
+use bitmap_writer::{Writer, Bitmap, Frame, Style, Align};
 
 use chrono::{Local, Timelike};
 
@@ -41,20 +41,20 @@ fn main() {
     let mut pixels: [u8; 8 * 8] = [0; 8 * 8];
     for i in 0..8 {
         let char = 
-            if i == 2 || i == 5 { 10 } else
-            if i < 2 { digits[i] } else
-            if i > 2 && i < 5 { digits[i - 1] } else
-            if i > 5 { digits[i - 2] } else
-            { 0 };         
+            if i == 2 || i == 5 { 10 }
+            else if i < 2 { digits[i] }
+            else if i > 2 && i < 5 { digits[i - 1] }
+            else if i > 5 { digits[i - 2] }
+            else { 0 };         
         for ii in 0..8 {
-            pixels[(i + ii * 8) as usize] = CHARS[(char as usize + ii * CHAR_AMOUNT) as usize];
+            pixels[i + ii * 8] = CHARS[char as usize + ii * CHAR_AMOUNT];
         }        
     }
 
     let bitmap = Bitmap::new(8 * 8, 8, &pixels);
 
     let mut w = Writer::new();
-    w.style(Style::UnicodeBlock1x2).frame(Frame::UnicodeBoldFrame).print(&bitmap);
+    w.style(Style::UnicodeBlock1x2).frame(Frame::UnicodeBoldFrame).title("clock", Align::Left).print(&bitmap);
     
 }
 
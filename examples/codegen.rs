@@ -0,0 +1,40 @@
+use bitmap_writer::{Writer, Bitmap, BitmapBuf, Codegen, Frame, Language, Literal, Png, Style};
+
+/// Convert a PNG file given as argument, or a built-in bitmap, into a Rust
+/// and a C array, with a terminal preview for review.
+fn main() {
+    let bitmap = match std::env::args().nth(1) {
+        Some(path) => {
+            let png = std::fs::read(path).expect("cannot read PNG file");
+            BitmapBuf::from_png(&png, 128, false).expect("cannot decode PNG file")
+        }
+        None => {
+            let bitmap = Bitmap::new(16, 8, &[
+                0b00010000, 0b00001000,
+                0b00111111, 0b11111100,
+                0b01111111, 0b11111110,
+                0b11111111, 0b11111111,
+                0b01111111, 0b11111110,
+                0b00000111, 0b11100000,
+                0b11001111, 0b11110000,
+                0b01111111, 0b11111000
+            ]);
+            BitmapBuf::from_png(&Png::new(&Writer::new(), &bitmap).to_vec(), 128, false).unwrap()
+        }
+    };
+    let bitmap = bitmap.as_bitmap();
+
+    let mut w = Writer::new();
+    w.style(Style::UnicodeBlock1x2).frame(Frame::UnicodeFrame).rulers(true).print(&bitmap);
+
+    println!();
+    Codegen::new(&w, &bitmap, "ICON").byte_aligned(true).print();
+
+    println!();
+    Codegen::new(&w, &bitmap, "ICON_SSD1306")
+        .language(Language::C)
+        .literal(Literal::Hex)
+        .pages(true)
+        .be(true)
+        .print();
+}
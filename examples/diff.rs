@@ -0,0 +1,38 @@
+use bitmap_writer::{Writer, Bitmap, Diff, Frame, Style};
+
+fn main() {
+    let expected = Bitmap::new(16, 8, &[
+        0b00010000, 0b00001000,
+        0b00111111, 0b11111100,
+        0b01111111, 0b11111110,
+        0b11111111, 0b11111111,
+        0b01111111, 0b11111110,
+        0b00000111, 0b11100000,
+        0b11001111, 0b11110000,
+        0b01111111, 0b11111000
+    ]);
+
+    let actual = Bitmap::new(16, 8, &[
+        0b00010000, 0b00001000,
+        0b00111111, 0b11111100,
+        0b01111111, 0b11111110,
+        0b11111111, 0b11111111,
+        0b01111111, 0b11111110,
+        0b00001111, 0b11100000,
+        0b11001111, 0b11110000,
+        0b01111111, 0b11110000
+    ]);
+
+    let mut w = Writer::new();
+    w.style(Style::UnicodeBlock1x2).frame(Frame::UnicodeFrame).rulers(true);
+
+    println!("Colors");
+    Diff::new(&w, &expected, &actual).print();
+
+    let mut w = Writer::new();
+    w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame);
+
+    println!();
+    println!("Glyphs");
+    Diff::new(&w, &expected, &actual).colors(false).print();
+}
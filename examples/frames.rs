@@ -1,4 +1,4 @@
-use bitmap_writer::{Writer, Bitmap, Frame, Style};
+use bitmap_writer::{Writer, Bitmap, Frame, FrameSpec, Style, Align};
 
 fn main() {
     let bitmap = Bitmap::new(16, 8, &[
@@ -13,20 +13,24 @@ fn main() {
     ]);
 
     let mut w = Writer::new();
-    w.style(Style::UnicodeBlock2x2);
+    w.style(Style::UnicodeBlock1x2);
 
-    println!("AsciiFrame");
-    w.frame(Frame::ASCIIFrame).print(&bitmap);
-    println!("UnicodeFrame");
-    w.frame(Frame::UnicodeFrame).print(&bitmap);
-    println!("UnicodeBoldFrame");
-    w.frame(Frame::UnicodeBoldFrame).print(&bitmap);
-    println!("UnicodeDoubleFrame");
-    w.frame(Frame::UnicodeDoubleUFrame).print(&bitmap);
-    println!("UnicodeBlockFrame");
-    w.frame(Frame::UnicodeBlockFrame).print(&bitmap);
-    println!("UnicodeShadeFrame");
-    w.frame(Frame::UnicodeShadeFrame).print(&bitmap);
+    w.frame(Frame::ASCIIFrame).title("ASCII", Align::Center).print(&bitmap);
+    w.frame(Frame::UnicodeFrame).title("Unicode", Align::Center).print(&bitmap);
+    w.frame(Frame::UnicodeBoldFrame).title("Bold", Align::Center).print(&bitmap);
+    w.frame(Frame::UnicodeDoubleUFrame).title("Double", Align::Center).print(&bitmap);
+    w.frame(Frame::UnicodeBlockFrame).title("Block", Align::Center).print(&bitmap);
+    w.frame(Frame::UnicodeShadeFrame).title("Shade", Align::Center).print(&bitmap);
+    w.frame(Frame::UnicodeRoundFrame).title("Round", Align::Center).print(&bitmap);
+    w.frame(Frame::Custom(['+', '=', '+', 'I', ' ', 'I', '+', '=', '+'])).title("Custom", Align::Center).print(&bitmap);
+    w.frame(Frame::Spec(FrameSpec {
+        corners: ['╭', '╮', '╰', '╯'],
+        top: "─┄",
+        bottom: "─┄",
+        left: "│┆",
+        right: "│┆",
+        fill: ' '
+    })).title("Spec", Align::Center).print(&bitmap);
     
 }
 
@@ -0,0 +1,36 @@
+use bitmap_writer::{Writer, Bitmap, Diff, Frame, Html, Style};
+
+fn main() {
+    let expected = Bitmap::new(16, 8, &[
+        0b00010000, 0b00001000,
+        0b00111111, 0b11111100,
+        0b01111111, 0b11111110,
+        0b11111111, 0b11111111,
+        0b01111111, 0b11111110,
+        0b00000111, 0b11100000,
+        0b11001111, 0b11110000,
+        0b01111111, 0b11111000
+    ]);
+
+    let actual = Bitmap::new(16, 8, &[
+        0b00010000, 0b00001000,
+        0b00111111, 0b11111100,
+        0b01111111, 0b11111110,
+        0b11111111, 0b11111111,
+        0b01111111, 0b11111110,
+        0b00001111, 0b11100000,
+        0b11001111, 0b11110000,
+        0b01111111, 0b11110000
+    ]);
+
+    let mut w = Writer::new();
+    w.style(Style::UnicodeBlock2x2).frame(Frame::UnicodeFrame);
+
+    println!("<!DOCTYPE html>");
+    println!("<html><head><meta charset=\"utf-8\"><title>bitmap_writer</title></head><body>");
+    println!("<h2>expected</h2>");
+    Html::new(&w, &expected).print();
+    println!("<h2>diff</h2>");
+    Html::diff(&Diff::new(&w, &expected, &actual)).print();
+    println!("</body></html>");
+}
@@ -0,0 +1,55 @@
+use bitmap_writer::{Writer, Bitmap, Frame, Layout, Panel, Style};
+
+fn main() {
+    let expected = Bitmap::new(16, 8, &[
+        0b00010000, 0b00001000,
+        0b00111111, 0b11111100,
+        0b01111111, 0b11111110,
+        0b11111111, 0b11111111,
+        0b01111111, 0b11111110,
+        0b00000111, 0b11100000,
+        0b11001111, 0b11110000,
+        0b01111111, 0b11111000
+    ]);
+
+    let actual = Bitmap::new(16, 8, &[
+        0b00010000, 0b00001000,
+        0b00111111, 0b11111100,
+        0b01111111, 0b11111110,
+        0b11111111, 0b11111111,
+        0b01111111, 0b11111110,
+        0b00000111, 0b11100000,
+        0b11001111, 0b11110000,
+        0b01111111, 0b11110000
+    ]);
+
+    let mut w = Writer::new();
+    w.style(Style::UnicodeBlock1x2).frame(Frame::UnicodeFrame);
+
+    println!("Side-by-side");
+    Layout::new(&[
+        Panel::new(&w, &expected).caption("expected"),
+        Panel::new(&w, &actual).caption("actual"),
+    ]).print();
+
+    let frames = [
+        Frame::ASCIIFrame, Frame::UnicodeFrame, Frame::UnicodeBoldFrame,
+        Frame::UnicodeDoubleUFrame, Frame::UnicodeBlockFrame, Frame::UnicodeShadeFrame
+    ];
+    let writers = frames.map(|frame| {
+        let mut w = Writer::new();
+        w.style(Style::UnicodeBlock2x2).frame(frame);
+        w
+    });
+
+    println!();
+    println!("Grid");
+    Layout::new(&[
+        Panel::new(&writers[0], &expected).caption("ASCII"),
+        Panel::new(&writers[1], &expected).caption("Unicode"),
+        Panel::new(&writers[2], &expected).caption("Bold"),
+        Panel::new(&writers[3], &expected).caption("Double"),
+        Panel::new(&writers[4], &expected).caption("Block"),
+        Panel::new(&writers[5], &expected).caption("Shade"),
+    ]).columns(3).spacing(4, 1).print();
+}
@@ -0,0 +1,26 @@
+use bitmap_writer::{Writer, Bitmap, Frame, Style};
+
+fn main() {
+    let icon = Bitmap::new(8, 8, &[
+        0b00111100,
+        0b01000010,
+        0b10100101,
+        0b10000001,
+        0b10100101,
+        0b10011001,
+        0b01000010,
+        0b00111100,
+    ]);
+
+    let mut w = Writer::new();
+    w.style(Style::UnicodeBlock1x2).frame(Frame::UnicodeRoundFrame);
+
+    println!("Flush");
+    w.print(&icon);
+
+    println!("Padding");
+    w.padding(1, 2, 1, 2).print(&icon);
+
+    println!("Padding with fill and margin");
+    w.padding_fill('·').margin(1, 0, 1, 4).print(&icon);
+}
@@ -0,0 +1,27 @@
+use bitmap_writer::{Writer, Bitmap, Frame, Pager, Style};
+
+fn main() {
+    const WIDTH: usize = 480;
+    const HEIGHT: usize = 320;
+
+    // Concentric rings and a grid, larger than most terminals.
+    let mut pixels = vec![0u8; WIDTH * HEIGHT / 8];
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let dx = x as isize - WIDTH as isize / 2;
+            let dy = y as isize - HEIGHT as isize / 2;
+            let ring = ((dx * dx + dy * dy) as f64).sqrt() as usize % 24 < 4;
+            let grid = x % 64 == 0 || y % 64 == 0;
+            if ring || grid {
+                pixels[(y * WIDTH + x) / 8] |= 0x80 >> (x % 8);
+            }
+        }
+    }
+
+    let bitmap = Bitmap::new(WIDTH, HEIGHT, &pixels);
+
+    let mut w = Writer::new();
+    w.style(Style::UnicodeBlock2x2).frame(Frame::UnicodeFrame);
+
+    Pager::new(w, &bitmap).run().unwrap();
+}
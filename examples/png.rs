@@ -0,0 +1,28 @@
+use bitmap_writer::{Writer, Bitmap, BitmapBuf, Frame, Png, Style};
+
+/// Preview a PNG file given as argument, or round-trip a built-in bitmap
+/// through PNG, as 1-bit bitmap in the terminal.
+fn main() {
+    let png = match std::env::args().nth(1) {
+        Some(path) => std::fs::read(path).expect("cannot read PNG file"),
+        None => {
+            let bitmap = Bitmap::new(16, 8, &[
+                0b00010000, 0b00001000,
+                0b00111111, 0b11111100,
+                0b01111111, 0b11111110,
+                0b11111111, 0b11111111,
+                0b01111111, 0b11111110,
+                0b00000111, 0b11100000,
+                0b11001111, 0b11110000,
+                0b01111111, 0b11111000
+            ]);
+            Png::new(&Writer::new(), &bitmap).scale(2).to_vec()
+        }
+    };
+
+    let bitmap = BitmapBuf::from_png(&png, 128, false).expect("cannot decode PNG file");
+    Writer::new()
+        .style(Style::UnicodeBlock2x2)
+        .frame(Frame::UnicodeFrame)
+        .print(&bitmap.as_bitmap());
+}
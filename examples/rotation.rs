@@ -0,0 +1,33 @@
+use bitmap_writer::{Writer, Bitmap, Frame, Rotation, Style};
+
+fn main() {
+    let arrow = Bitmap::new(12, 6, &[
+        0b00000010, 0b0000_0000,
+        0b00000011, 0b0000_0000,
+        0b11111111, 0b1100_0000,
+        0b11111111, 0b1100_0000,
+        0b00000011, 0b0000_0000,
+        0b00000010, 0b0000_0000,
+    ]);
+
+    let mut w = Writer::new();
+    w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame).byte_aligned(true);
+
+    for (name, rotation) in [
+        ("Rotate0", Rotation::Rotate0),
+        ("Rotate90", Rotation::Rotate90),
+        ("Rotate180", Rotation::Rotate180),
+        ("Rotate270", Rotation::Rotate270),
+    ] {
+        println!("{}", name);
+        w.rotate(rotation).print(&arrow);
+    }
+
+    w.rotate(Rotation::Rotate0);
+    println!("flip_horizontal");
+    w.flip_horizontal(true).print(&arrow);
+    println!("flip_vertical");
+    w.flip_horizontal(false).flip_vertical(true).print(&arrow);
+    println!("transpose");
+    w.flip_vertical(false).transpose(true).print(&arrow);
+}
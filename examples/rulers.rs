@@ -0,0 +1,32 @@
+use bitmap_writer::{Writer, Bitmap, Frame, Style};
+
+fn main() {
+    let bitmap = Bitmap::new(32, 16, &[
+        0b11111111, 0b11111111, 0b11111111, 0b11111111,
+        0b10000000, 0b00000000, 0b00000000, 0b00000001,
+        0b10111100, 0b00000000, 0b00000000, 0b00111101,
+        0b10100100, 0b00000000, 0b00000000, 0b00100101,
+        0b10100100, 0b00000000, 0b00000000, 0b00100101,
+        0b10111100, 0b00000000, 0b00000000, 0b00111101,
+        0b10000000, 0b00000000, 0b00000000, 0b00000001,
+        0b10000000, 0b00000001, 0b10000000, 0b00000001,
+        0b10000000, 0b00000001, 0b10000000, 0b00000001,
+        0b10000000, 0b00000000, 0b00000000, 0b00000001,
+        0b10111100, 0b00000000, 0b00000000, 0b00111101,
+        0b10100100, 0b00000000, 0b00000000, 0b00100101,
+        0b10100100, 0b00000000, 0b00000000, 0b00100101,
+        0b10111100, 0b00000000, 0b00000000, 0b00111101,
+        0b10000000, 0b00000000, 0b00000000, 0b00000001,
+        0b11111111, 0b11111111, 0b11111111, 0b11111111,
+    ]);
+
+    let mut w = Writer::new();
+    w.frame(Frame::UnicodeFrame).rulers(true);
+
+    println!("UnicodeBlock1x1");
+    w.style(Style::UnicodeBlock1x1).print(&bitmap);
+    println!("UnicodeBlock2x2");
+    w.style(Style::UnicodeBlock2x2).print(&bitmap);
+    println!("UnicodeBlock2x2, viewport at 8,4");
+    w.viewport(8, 4, 24, 12).print(&bitmap);
+}
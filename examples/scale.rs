@@ -0,0 +1,30 @@
+use bitmap_writer::{Writer, Bitmap, Frame, Scale, Style};
+
+fn main() {
+    let icon = Bitmap::new(8, 8, &[
+        0b00111100,
+        0b01000010,
+        0b10100101,
+        0b10000001,
+        0b10100101,
+        0b10011001,
+        0b01000010,
+        0b00111100,
+    ]);
+
+    let mut w = Writer::new();
+    w.style(Style::UnicodeSextant2x3).frame(Frame::UnicodeFrame);
+
+    println!("Original");
+    w.scale(Scale::Original).print(&icon);
+    println!("Up(3)");
+    w.scale(Scale::Up(3)).print(&icon);
+
+    w.style(Style::ASCII1x1('#'));
+    println!("DownNearest(2)");
+    w.scale(Scale::DownNearest(2)).print(&icon);
+    println!("DownOr(2)");
+    w.scale(Scale::DownOr(2)).print(&icon);
+    println!("DownMajority(2)");
+    w.scale(Scale::DownMajority(2)).print(&icon);
+}
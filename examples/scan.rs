@@ -0,0 +1,25 @@
+use bitmap_writer::{FileRegion, Frame, Scan, Style, Writer};
+
+/// Scan a file given as argument, or built-in data with an icon hidden in
+/// noise, for 16x16 bitmaps at every 2 bytes, the row length of the icon.
+fn main() {
+    let mut w = Writer::new();
+    w.style(Style::UnicodeBlock1x2).frame(Frame::UnicodeFrame);
+
+    match std::env::args().nth(1) {
+        Some(path) => {
+            let region = FileRegion::read(path, 0, Some(4096)).expect("cannot read file");
+            region.scan(&w, 16, 16).step(Some(2)).count(Some(16)).print();
+        }
+        None => {
+            let mut data = [0u8; 64];
+            for (i, byte) in data.iter_mut().enumerate() { *byte = (i as u8).wrapping_mul(73) ^ 0x5a; }
+            let icon: [u16; 16] = [
+                0x0000, 0x07e0, 0x1818, 0x2004, 0x4812, 0x4812, 0x8001, 0x8001,
+                0x8001, 0x9009, 0x8811, 0x47e2, 0x4002, 0x2004, 0x1818, 0x07e0
+            ];
+            for (row, bits) in icon.iter().enumerate() { data[20 + row * 2..22 + row * 2].copy_from_slice(&bits.to_be_bytes()); }
+            Scan::new(&w, &data, 16, 16).start(16).step(Some(2)).count(Some(3)).print();
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use bitmap_writer::{Writer, Bitmap, Svg};
+
+fn main() {
+    let bitmap = Bitmap::new(16, 8, &[
+        0b00010000, 0b00001000,
+        0b00111111, 0b11111100,
+        0b01111111, 0b11111110,
+        0b11111111, 0b11111111,
+        0b01111111, 0b11111110,
+        0b00000111, 0b11100000,
+        0b11001111, 0b11110000,
+        0b01111111, 0b11111000
+    ]);
+
+    let w = Writer::new();
+    Svg::new(&w, &bitmap)
+        .pixel_size(10)
+        .colors("#1d3557", Some("#f1faee"))
+        .grid(Some("#a8dadc"))
+        .print();
+}
@@ -15,7 +15,7 @@ impl Bitmap <'_> {
     ///
     /// # Returns
     /// - Bitmap instance.
-    pub fn new(width: usize, height: usize, pixels: &[u8]) -> Bitmap {
+    pub fn new(width: usize, height: usize, pixels: &[u8]) -> Bitmap<'_> {
         return Bitmap { _w: width, _h: height, _pixels: pixels };
     }
 
@@ -34,4 +34,122 @@ impl Bitmap <'_> {
     pub fn height(self) -> usize {
         return self._h;
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// A bitmap that owns its pixel data, for example the result of parsing.
+///
+/// The pixels are stored little-endian and not byte-aligned, the defaults of
+/// `Writer`, so `as_bitmap()` can be written by a writer with default
+/// settings.
+///
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BitmapBuf {
+    pub(crate) _w: usize,
+    pub(crate) _h: usize,
+    pub(crate) _pixels: Vec<u8>
+}
+
+#[cfg(feature = "alloc")]
+impl BitmapBuf {
+    /// Construct a new bitmap with all pixels unset.
+    ///
+    /// # Arguments
+    /// - `width`: Pixel width.
+    /// - `height`: Pixel height.
+    ///
+    /// # Returns
+    /// - BitmapBuf instance.
+    pub fn new(width: usize, height: usize) -> BitmapBuf {
+        return BitmapBuf { _w: width, _h: height, _pixels: vec![0; (width * height).div_ceil(8)] };
+    }
+
+    /// Get the bitmap's width.
+    ///
+    /// # Returns
+    /// - Pixel width of the bitmap.
+    pub fn width(&self) -> usize {
+        return self._w;
+    }
+
+    /// Get the bitmap's height.
+    ///
+    /// # Returns
+    /// - Pixel height of the bitmap.
+    pub fn height(&self) -> usize {
+        return self._h;
+    }
+
+    /// Get the bytes containing the bitmap data.
+    ///
+    /// # Returns
+    /// - Little-endian, non-byte-aligned pixel data.
+    pub fn pixels(&self) -> &[u8] {
+        return &self._pixels;
+    }
+
+    /// Get whether a pixel is set. Pixels outside of the bitmap are unset.
+    ///
+    /// # Arguments
+    /// - `x`: Pixel column.
+    /// - `y`: Pixel row.
+    ///
+    /// # Returns
+    /// - `true` if the pixel is set.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        if x >= self._w || y >= self._h { return false; }
+        let bit = y * self._w + x;
+        return self._pixels[bit / 8] & (0x80 >> (bit % 8)) != 0;
+    }
+
+    /// Set or unset a pixel. Pixels outside of the bitmap are ignored.
+    ///
+    /// # Arguments
+    /// - `x`: Pixel column.
+    /// - `y`: Pixel row.
+    /// - `state`: Set to `true` to set the pixel.
+    pub fn set_pixel(&mut self, x: usize, y: usize, state: bool) {
+        if x >= self._w || y >= self._h { return; }
+        let bit = y * self._w + x;
+        if state {
+            self._pixels[bit / 8] |= 0x80 >> (bit % 8);
+        } else {
+            self._pixels[bit / 8] &= !(0x80 >> (bit % 8));
+        }
+    }
+
+    /// Copy a rectangular region into a new bitmap. The region is clipped to
+    /// the bitmap's size.
+    ///
+    /// # Arguments
+    /// - `x`: Left-most pixel column of the region.
+    /// - `y`: Top-most pixel row of the region.
+    /// - `width`: Pixel width of the region.
+    /// - `height`: Pixel height of the region.
+    ///
+    /// # Returns
+    /// - BitmapBuf instance.
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> BitmapBuf {
+        let x = x.min(self._w);
+        let y = y.min(self._h);
+        let mut cropped = BitmapBuf::new(width.min(self._w - x), height.min(self._h - y));
+        for yy in 0..cropped._h {
+            for xx in 0..cropped._w {
+                cropped.set_pixel(xx, yy, self.pixel(x + xx, y + yy));
+            }
+        }
+        return cropped;
+    }
+
+    /// Borrow the bitmap for writing.
+    ///
+    /// # Returns
+    /// - Bitmap instance.
+    pub fn as_bitmap(&self) -> Bitmap<'_> {
+        return Bitmap::new(self._w, self._h, &self._pixels);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::parse::ParseError;
+
+use alloc::vec::Vec;
+
+/// Parse bytes pasted as text, for example from a log line, source code or
+/// a hex dump, to build a `Bitmap` from them.
+///
+/// Three notations are understood, and can be mixed:
+/// - `xxd` output: lines starting with an offset and a colon, followed by
+///   groups of hex digits. The text column on the right is skipped.
+/// - Literals: if the text contains any `0x` or `0b` literal, e.g.
+///   `0x3F, 0xFC` or `0b0011_1111`, only the literals are read and all
+///   other text, like brackets, names or log prefixes, is skipped. Type
+///   suffixes like `u8` are allowed. Literals with more digits than a byte
+///   hold several bytes, the first written first.
+/// - Plain hex: otherwise the text consists of bytes as hex digits,
+///   separated by spaces, commas or semicolons, like `3f fc` or the
+///   continuous lines of `xxd -p`.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, parse_bytes};
+///
+/// let bytes = parse_bytes("[INFO] icon: [0x3F, 0xFC, 0b0001_1000, 0x18u8]").unwrap();
+/// assert_eq!(bytes, [0x3f, 0xfc, 0x18, 0x18]);
+/// let bitmap = Bitmap::new(16, 2, &bytes);
+///
+/// let xxd = "\
+/// 00000000: 3ffc 1818 0000 0000 0000 0000 0000 0000  ?...............
+/// 00000010: 7e                                       ~
+/// ";
+/// assert_eq!(parse_bytes(xxd).unwrap()[..4], [0x3f, 0xfc, 0x18, 0x18]);
+/// assert_eq!(parse_bytes(xxd).unwrap().len(), 17);
+///
+/// assert_eq!(parse_bytes("1234: 0x3F, 0xFC").unwrap(), [0x3f, 0xfc]);
+/// assert_eq!(parse_bytes("3f fc\n1818").unwrap(), [0x3f, 0xfc, 0x18, 0x18]);
+/// assert_eq!(parse_bytes("3f fg").unwrap_err().to_string(), "invalid byte at line 1, column 4");
+/// ```
+///
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+///
+/// # Arguments
+/// - `text`: The text to parse.
+///
+/// # Returns
+/// - The bytes, or the position of the first value that is not a byte.
+pub fn parse_bytes(text: &str) -> Result<Vec<u8>, ParseError> {
+    let literals = text.lines().any(|line| {
+        let chars: Vec<char> = line.chars().collect();
+        return (0..chars.len()).any(|i| _literal(&chars, i).is_some());
+    });
+
+    let mut bytes = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+        let error = |column: usize| ParseError::InvalidByte { line: index + 1, column: column + 1 };
+
+        if let Some(mut i) = _xxd_data(&chars) {
+            while i < chars.len() && chars[i] != ' ' {
+                let end = chars[i..].iter().position(|c| *c == ' ').map_or(chars.len(), |n| i + n);
+                _push_digits(&mut bytes, &chars[i..end], 16).ok_or(error(i))?;
+                if chars.get(end + 1).is_none_or(|c| *c == ' ') { break; }
+                i = end + 1;
+            }
+        } else if literals {
+            let mut i = 0;
+            while i < chars.len() {
+                match _literal(&chars, i) {
+                    Some((radix, digits, end)) => {
+                        _push_digits(&mut bytes, &digits, radix).ok_or(error(i))?;
+                        i = end;
+                    }
+                    None => { i += 1; }
+                }
+            }
+        } else {
+            let mut i = 0;
+            while i < chars.len() {
+                if chars[i].is_whitespace() || matches!(chars[i], ',' | ';') {
+                    i += 1;
+                    continue;
+                }
+                let end = chars[i..].iter().position(|c| c.is_whitespace() || matches!(c, ',' | ';')).map_or(chars.len(), |n| i + n);
+                _push_digits(&mut bytes, &chars[i..end], 16).ok_or(error(i))?;
+                i = end;
+            }
+        }
+    }
+    return Ok(bytes);
+}
+
+/// Start of the hex groups if the line is a line of `xxd` output, which
+/// starts with a hex offset, a colon and a space, followed by a group of
+/// bare hex digits. Lines like `1234: 0x3F` are left to the other notations.
+fn _xxd_data(chars: &[char]) -> Option<usize> {
+    let start = chars.iter().position(|c| *c != ' ')?;
+    let colon = start + chars[start..].iter().position(|c| !c.is_ascii_hexdigit())?;
+    if colon == start || chars[colon] != ':' || chars.get(colon + 1) != Some(&' ') { return None; }
+    let group = chars[colon + 2..].iter().take_while(|c| **c != ' ');
+    if group.clone().count() == 0 || !group.clone().all(char::is_ascii_hexdigit) { return None; }
+    return Some(colon + 2);
+}
+
+/// A `0x` or `0b` literal starting at the given position, as its radix, its
+/// digits and the position after it.
+fn _literal(chars: &[char], i: usize) -> Option<(u32, Vec<char>, usize)> {
+    if chars.get(i) != Some(&'0') || i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == '_') { return None; }
+    let radix = match chars.get(i + 1) {
+        Some('x' | 'X') => 16,
+        Some('b' | 'B') => 2,
+        _ => { return None; }
+    };
+    let mut end = i + 2;
+    let mut digits = Vec::new();
+    while let Some(c) = chars.get(end).filter(|c| c.is_digit(radix) || **c == '_') {
+        if *c != '_' { digits.push(*c); }
+        end += 1;
+    }
+    if digits.is_empty() { return None; }
+    if chars.get(end).is_some_and(|c| matches!(c, 'u' | 'i' | 'U' | 'L' | 'l')) {
+        end += 1;
+        while chars.get(end).is_some_and(|c| c.is_ascii_digit() || matches!(c, 'L' | 'l')) { end += 1; }
+    }
+    if chars.get(end).is_some_and(|c| c.is_alphanumeric() || *c == '_') { return None; }
+    return Some((radix, digits, end));
+}
+
+/// Append the bytes written as digits, a single byte if they fit, otherwise
+/// one byte for every 2 hex or 8 binary digits.
+fn _push_digits(bytes: &mut Vec<u8>, digits: &[char], radix: u32) -> Option<()> {
+    let (per_byte, shift) = if radix == 16 { (2, 4) } else { (8, 1) };
+    if digits.is_empty() || (digits.len() > per_byte && !digits.len().is_multiple_of(per_byte)) { return None; }
+    for chunk in digits.chunks(per_byte) {
+        let mut byte = 0u8;
+        for c in chunk { byte = (byte << shift) | c.to_digit(radix)? as u8; }
+        bytes.push(byte);
+    }
+    return Some(());
+}
@@ -0,0 +1,176 @@
+use crate::source::Image;
+use crate::writer::Writer;
+
+/// Number of characters rendered at once by `Chars`.
+const CHUNK: usize = 32;
+
+/// ```no_std``` compatible and without allocation.
+impl<'a> Writer<'a> {
+    /// Iterate over the characters of the output of `write(..)`, including
+    /// frame characters, line breaks and ANSI commands, without allocating.
+    ///
+    /// The characters are rendered lazily in small chunks, so the output can
+    /// be pushed out piece by piece, e.g. into the buffer of a UART DMA
+    /// transfer, and needs only about 200 bytes of memory.
+    ///
+    /// In exchange, a text line of N characters is rendered about N / 32
+    /// times, each time reading its pixels again. With a `RowBuffer`, rows
+    /// are read from the source again when a text line covers more rows than
+    /// the buffer keeps, e.g. with `Scale::DownOr(..)` or
+    /// `Scale::DownMajority(..)`.
+    ///
+    /// ```
+    /// let bitmap = bitmap_writer::Bitmap::new(8, 2, &[0b00111100, 0b01000010]);
+    /// let w = bitmap_writer::Writer::new();
+    ///
+    /// let mut buffer = [0u8; 16];
+    /// let mut length = 0;
+    /// for c in w.chars(&bitmap) {
+    ///     if length + c.len_utf8() > buffer.len() {
+    ///         // Send buffer[..length], then start over.
+    ///         length = 0;
+    ///     }
+    ///     length += c.encode_utf8(&mut buffer[length..]).len();
+    /// }
+    /// assert_eq!(w.chars(&bitmap).collect::<String>(), " ▄▀▀▀▀▄ \n");
+    ///
+    /// // Lines longer than a chunk of 32 characters, with ANSI commands.
+    /// let wide = bitmap_writer::Bitmap::new(80, 4, &[0x5a; 40]);
+    /// let mut w = bitmap_writer::Writer::new();
+    /// w.frame(bitmap_writer::Frame::UnicodeFrame).ansi_position(2, 5);
+    /// let text = format!("{}", w.display(&wide));
+    /// assert_eq!(text.lines().map(|line| line.chars().count()).max(), Some(88));
+    /// assert_eq!(w.chars(&wide).collect::<String>(), text);
+    /// ```
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to write.
+    ///
+    /// # Returns
+    /// - Iterator over the characters.
+    pub fn chars<'b, I: Image + ?Sized>(&'b self, bitmap: &'b I) -> impl Iterator<Item = char> + 'b {
+        return Chars::new(self, bitmap, 0, self._parts(bitmap));
+    }
+
+    /// Iterate over the text lines of the output of `write(..)`, each an
+    /// iterator over its characters like `chars(..)`, including the line
+    /// break. The ANSI commands storing and restoring the cursor position
+    /// are part of the first and last line.
+    ///
+    /// ```
+    /// let bitmap = bitmap_writer::Bitmap::new(8, 2, &[0b00111100, 0b01000010]);
+    /// let mut w = bitmap_writer::Writer::new();
+    /// w.frame(bitmap_writer::Frame::ASCIIFrame);
+    /// for line in w.lines(&bitmap) {
+    ///     // Send a line at a time.
+    ///     assert_eq!(line.count(), 11);
+    /// }
+    ///
+    /// let wide = bitmap_writer::Bitmap::new(80, 4, &[0x5a; 40]);
+    /// w.ansi_position(2, 5).ansi_position_restore(true);
+    /// let lines: Vec<String> = w.lines(&wide).map(|line| line.collect()).collect();
+    /// assert_eq!(lines.len(), 4);
+    /// assert_eq!(lines.concat(), format!("{}", w.display(&wide)));
+    /// ```
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to write.
+    ///
+    /// # Returns
+    /// - Iterator over the text lines.
+    pub fn lines<'b, I: Image + ?Sized>(&'b self, bitmap: &'b I) -> impl Iterator<Item = impl Iterator<Item = char> + 'b> + 'b {
+        let lines = self._lines(bitmap);
+        return (0..lines).map(move |line| {
+            let start = if line == 0 { 0 } else { line + 1 };
+            let end = if line == lines - 1 { lines + 2 } else { line + 2 };
+            return Chars::new(self, bitmap, start, end);
+        });
+    }
+}
+
+/// Renders the parts of a writer's output, see `Writer::_write_part(..)`,
+/// a chunk of characters at a time. Every chunk renders the part again
+/// from its start, skipping the characters already returned.
+struct Chars<'a, I: Image + ?Sized> {
+    _writer: &'a Writer<'a>,
+    _bitmap: &'a I,
+    _part: usize,
+    _end: usize,
+    _offset: usize,
+    _chunk: [char; CHUNK],
+    _length: usize,
+    _index: usize
+}
+
+impl<'a, I: Image + ?Sized> Chars<'a, I> {
+    fn new(writer: &'a Writer<'a>, bitmap: &'a I, start: usize, end: usize) -> Chars<'a, I> {
+        return Chars { _writer: writer, _bitmap: bitmap, _part: start, _end: end, _offset: 0, _chunk: [' '; CHUNK], _length: 0, _index: 0 };
+    }
+}
+
+impl<I: Image + ?Sized> Iterator for Chars<'_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        while self._index == self._length {
+            if self._part >= self._end { return None; }
+            let mut capture = Capture { _skip: self._offset, _chunk: &mut self._chunk, _length: 0, _full: false, _utf8: [0; 4], _pending: 0 };
+            let _ = self._writer._write_part(&mut capture, self._bitmap, self._part);
+            (self._length, self._index) = (capture._length, 0);
+            if capture._full {
+                self._offset += CHUNK;
+            } else {
+                (self._part, self._offset) = (self._part + 1, 0);
+            }
+        }
+        self._index += 1;
+        return Some(self._chunk[self._index - 1]);
+    }
+}
+
+/// Sink keeping a chunk of the characters written after skipping a number
+/// of them, which fails once the chunk is full to stop the rendering.
+struct Capture<'c> {
+    _skip: usize,
+    _chunk: &'c mut [char; CHUNK],
+    _length: usize,
+    _full: bool,
+    _utf8: [u8; 4],
+    _pending: usize
+}
+
+impl Capture<'_> {
+    /// Decode the bytes of UTF-8 text, which may end or start in the middle
+    /// of a character.
+    ///
+    /// # Returns
+    /// - `false` once the chunk is full.
+    fn _feed(&mut self, bytes: &[u8]) -> bool {
+        for byte in bytes {
+            self._utf8[self._pending] = *byte;
+            self._pending += 1;
+            let expected = match self._utf8[0] { 0xf0.. => 4, 0xe0.. => 3, 0xc0.. => 2, _ => 1 };
+            if self._pending < expected { continue; }
+            let c = core::str::from_utf8(&self._utf8[..self._pending]).ok().and_then(|s| s.chars().next()).unwrap_or('\u{fffd}');
+            self._pending = 0;
+
+            if self._skip > 0 {
+                self._skip -= 1;
+            } else if self._length < CHUNK {
+                self._chunk[self._length] = c;
+                self._length += 1;
+            } else {
+                self._full = true;
+                return false;
+            }
+        }
+        return true;
+    }
+}
+
+impl core::fmt::Write for Capture<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if !self._feed(s.as_bytes()) { return Err(core::fmt::Error); }
+        return Ok(());
+    }
+}
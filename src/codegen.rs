@@ -0,0 +1,263 @@
+use crate::{Language, Literal};
+use crate::bitmap::Bitmap;
+use crate::sink::{Sink, _write_to};
+use crate::writer::{Write, WriteResult, Writer};
+
+/// Write a bitmap as source code of a byte array, for example to include
+/// images into firmware.
+///
+/// Pixels are read as the given writer sees them, so endianess,
+/// byte-alignment, flipping, rotation, scaling and the viewport apply, while
+/// style, frame and the other text settings are ignored. The bytes are then
+/// encoded with the codegen's own settings, so a bitmap can be converted
+/// between formats. A `Writer` with the same `be(..)` and `byte_aligned(..)`
+/// settings reads the generated row layout back.
+///
+/// Besides the array, constants `<NAME>_WIDTH` and `<NAME>_HEIGHT` hold the
+/// size in pixels. Rows start on a new line of source code, so binary
+/// literals show the image.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, Codegen, Language, Literal, Writer};
+///
+/// let bitmap = Bitmap::new(16, 2, &[0b00010000, 0b00001000, 0b00111111, 0b11111100]);
+/// let w = Writer::new();
+/// let mut codegen = Codegen::new(&w, &bitmap, "ICON");
+/// codegen.language(Language::C).literal(Literal::Hex).byte_aligned(true);
+///
+/// let mut source = String::new();
+/// codegen.write(&mut source);
+/// assert_eq!(source, "\
+/// #include <stdint.h>
+///
+/// #define ICON_WIDTH 16
+/// #define ICON_HEIGHT 2
+/// const uint8_t ICON[4] = {
+///     0x10, 0x08,
+///     0x3f, 0xfc,
+/// };
+/// ");
+/// ```
+pub struct Codegen<'a> {
+    _writer: &'a Writer<'a>,
+    _bitmap: &'a Bitmap<'a>,
+    _name: &'a str,
+    _language: Language,
+    _literal: Literal,
+    _use_be: bool,
+    _byte_aligned: bool,
+    _pages: bool
+}
+
+impl<'a> Codegen<'a> {
+    /// Construct a code generator writing Rust source with binary literals,
+    /// little-endian and not byte-aligned, like the defaults of `Writer`.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to read the bitmap.
+    /// - `bitmap`: The bitmap to write.
+    /// - `name`: Name of the array, which must be a valid identifier. Constants are conventionally upper case.
+    ///
+    /// # Returns
+    /// - Codegen instance.
+    pub fn new(writer: &'a Writer<'a>, bitmap: &'a Bitmap<'a>, name: &'a str) -> Codegen<'a> {
+        return Codegen {
+            _writer: writer, _bitmap: bitmap, _name: name, _language: Language::Rust, _literal: Literal::Binary,
+            _use_be: false, _byte_aligned: false, _pages: false
+        };
+    }
+
+    /// Set the programming language.
+    ///
+    /// # Arguments
+    /// - `language`: See `bitmap_writer::Language`.
+    ///
+    /// # Returns
+    /// - Reference to codegen.
+    pub fn language(&mut self, language: Language) -> &mut Self {
+        self._language = language;
+        return self;
+    }
+
+    /// Set the notation of the bytes.
+    ///
+    /// # Arguments
+    /// - `literal`: See `bitmap_writer::Literal`.
+    ///
+    /// # Returns
+    /// - Reference to codegen.
+    pub fn literal(&mut self, literal: Literal) -> &mut Self {
+        self._literal = literal;
+        return self;
+    }
+
+    /// Store the first pixel of every byte in the least significant bit,
+    /// like `Writer::be(..)`. Otherwise it is stored in the most significant
+    /// bit.
+    ///
+    /// # Arguments
+    /// - `state`: `true` for big-endian.
+    ///
+    /// # Returns
+    /// - Reference to codegen.
+    pub fn be(&mut self, state: bool) -> &mut Self {
+        self._use_be = state;
+        return self;
+    }
+
+    /// Start every row of pixels with a new byte, like
+    /// `Writer::byte_aligned(..)`. Ignored for the page layout.
+    ///
+    /// # Arguments
+    /// - `state`: `true` for byte-aligned rows.
+    ///
+    /// # Returns
+    /// - Reference to codegen.
+    pub fn byte_aligned(&mut self, state: bool) -> &mut Self {
+        self._byte_aligned = state;
+        return self;
+    }
+
+    /// Use the page layout of displays like the SSD1306: every byte holds a
+    /// column of 8 pixels, from left to right, and each band of 8 rows
+    /// forms a page, from top to bottom. The first pixel of a byte is the
+    /// top one, see `be(..)` for its bit. Otherwise bytes hold rows of
+    /// pixels.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Codegen, Writer};
+    ///
+    /// // 3x10 pixels: a full column, a dot at both ends and a short bar.
+    /// let bitmap = Bitmap::new(3, 10, &[
+    ///     0b11100000, 0b10100000, 0b10100000, 0b10100000, 0b10000000,
+    ///     0b10000000, 0b10000000, 0b10000000, 0b10000000, 0b11000000
+    /// ]);
+    /// let mut w = Writer::new();
+    /// w.byte_aligned(true);
+    ///
+    /// let mut source = String::new();
+    /// Codegen::new(&w, &bitmap, "BAR").pages(true).be(true).write(&mut source);
+    /// assert_eq!(source, "\
+    /// pub const BAR_WIDTH: usize = 3;
+    /// pub const BAR_HEIGHT: usize = 10;
+    /// pub const BAR: [u8; 6] = [
+    ///     0b11111111, 0b00000001, 0b00001111,
+    ///     0b00000011, 0b00000010, 0b00000000,
+    /// ];
+    /// ");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `state`: `true` for the page layout.
+    ///
+    /// # Returns
+    /// - Reference to codegen.
+    pub fn pages(&mut self, state: bool) -> &mut Self {
+        self._pages = state;
+        return self;
+    }
+
+    /// Write the source code.
+    ///
+    /// # Arguments
+    /// - `writer`: A sink implementing `core::fmt::Write` or `std::io::Write`, see `bitmap_writer::Sink`. It will contain the resulting source code.
+    pub fn write<K>(&self, writer: &mut impl Sink<K>) {
+        _write_to(writer, |w| self._write_source(w));
+    }
+
+    fn _write_source(&self, w: &mut impl Write) -> WriteResult {
+        let (_left, _top, width, height) = self._writer._region(self._bitmap);
+        let count = self._byte_count();
+        let name = self._name;
+
+        match self._language {
+            Language::Rust => {
+                writeln!(w, "pub const {}_WIDTH: usize = {};", name, width)?;
+                writeln!(w, "pub const {}_HEIGHT: usize = {};", name, height)?;
+                write!(w, "pub const {}: [u8; {}] = [", name, count)?;
+            }
+            Language::C => {
+                writeln!(w, "#include <stdint.h>")?;
+                writeln!(w)?;
+                writeln!(w, "#define {}_WIDTH {}", name, width)?;
+                writeln!(w, "#define {}_HEIGHT {}", name, height)?;
+                write!(w, "const uint8_t {}[{}] = {{", name, count)?;
+            }
+        }
+
+        for index in 0..count {
+            if index == 0 || self._line_start(index) { write!(w, "\n    ")?; } else { write!(w, " ")?; }
+            match self._literal {
+                Literal::Binary => { write!(w, "0b{:08b},", self._byte(index))?; }
+                Literal::Hex => { write!(w, "0x{:02x},", self._byte(index))?; }
+            }
+        }
+        writeln!(w)?;
+
+        match self._language {
+            Language::Rust => { writeln!(w, "];")?; }
+            Language::C => { writeln!(w, "}};")?; }
+        }
+        return Ok(());
+    }
+
+    fn _byte_count(&self) -> usize {
+        let (_left, _top, width, height) = self._writer._region(self._bitmap);
+        if self._pages { return width * height.div_ceil(8); }
+        if self._byte_aligned { return width.div_ceil(8) * height; }
+        return (width * height).div_ceil(8);
+    }
+
+    /// Whether the byte starts a new line of source code: the first byte of
+    /// a row or page, and after as many bytes as fit onto a line.
+    fn _line_start(&self, index: usize) -> bool {
+        let (_left, _top, width, _height) = self._writer._region(self._bitmap);
+        let per_line = if self._literal == Literal::Binary { 8 } else { 16 };
+        let row = if self._pages { width } else if self._byte_aligned { width.div_ceil(8) } else { 0 };
+        return match row {
+            0 => index.is_multiple_of(per_line),
+            row => (index % row).is_multiple_of(per_line)
+        };
+    }
+
+    /// Encode a single byte of the array.
+    fn _byte(&self, index: usize) -> u8 {
+        let (left, top, width, height) = self._writer._region(self._bitmap);
+        let mut byte = 0;
+        for bit in 0..8 {
+            let (x, y) = if self._pages {
+                (index % width, index / width * 8 + bit)
+            } else {
+                let stride = if self._byte_aligned { width.div_ceil(8) * 8 } else { width };
+                ((index * 8 + bit) % stride, (index * 8 + bit) / stride)
+            };
+            if x < width && y < height && self._writer._sample(self._bitmap, left + x, top + y) {
+                byte |= if self._use_be { 1 << bit } else { 0x80 >> bit };
+            }
+        }
+        return byte;
+    }
+}
+
+#[cfg(feature = "std")]
+use crate::sink::_print_to;
+
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+impl Codegen<'_> {
+    /// Print the source code out to terminal on **stdout**, ignoring errors.
+    pub fn print(&self) {
+        let _ = self.print_to(&mut std::io::stdout().lock());
+    }
+
+    /// Print the source code to any stream, see `Writer::print_to(..)`.
+    ///
+    /// # Arguments
+    /// - `stream`: The stream to write to.
+    ///
+    /// # Returns
+    /// - The error of the stream, apart from a broken pipe.
+    pub fn print_to(&self, stream: &mut impl std::io::Write) -> std::io::Result<()> {
+        return _print_to(stream, |w| self._write_source(w));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::bitmap::BitmapBuf;
+
+/// Error when decoding an image file into a bitmap.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecodeError {
+    /// The data does not start like a file of the expected format.
+    Signature,
+    /// The header is missing, describes an invalid image or a variant of
+    /// the format that is not supported.
+    Header,
+    /// The image data is missing, truncated or corrupt.
+    Data
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return match self {
+            DecodeError::Signature => write!(f, "unknown file format"),
+            DecodeError::Header => write!(f, "invalid or unsupported image header"),
+            DecodeError::Data => write!(f, "missing, truncated or corrupt image data")
+        };
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+impl BitmapBuf {
+    /// Decode a portable bitmap file, either plain text (`P1`) or binary
+    /// (`P4`). Black pixels, stored as `1`, are set.
+    ///
+    /// ```
+    /// let bitmap = bitmap_writer::BitmapBuf::from_pbm(b"P1\n# icon\n4 2\n0110\n1001\n").unwrap();
+    /// assert_eq!((bitmap.width(), bitmap.height()), (4, 2));
+    /// assert!(bitmap.pixel(1, 0) && bitmap.pixel(0, 1));
+    /// ```
+    ///
+    /// # Arguments
+    /// - `pbm`: The content of the PBM file.
+    ///
+    /// # Returns
+    /// - The bitmap, or why the file could not be decoded.
+    pub fn from_pbm(pbm: &[u8]) -> Result<BitmapBuf, DecodeError> {
+        let binary = match pbm.get(..2) {
+            Some(b"P1") => false,
+            Some(b"P4") => true,
+            _ => { return Err(DecodeError::Signature); }
+        };
+
+        let mut position = 2;
+        let mut size = [0usize; 2];
+        for value in size.iter_mut() {
+            _skip_pbm_space(pbm, &mut position);
+            let start = position;
+            while pbm.get(position).is_some_and(|c| c.is_ascii_digit()) { position += 1; }
+            *value = core::str::from_utf8(&pbm[start..position]).ok().and_then(|v| v.parse().ok()).ok_or(DecodeError::Header)?;
+        }
+        let [width, height] = size;
+        width.checked_mul(height).ok_or(DecodeError::Header)?;
+
+        if pbm.len().saturating_sub(position) < if binary { width.div_ceil(8) * height } else { width * height } {
+            return Err(DecodeError::Data);
+        }
+
+        let mut bitmap = BitmapBuf::new(width, height);
+        if binary {
+            let stride = width.div_ceil(8);
+            let data = pbm.get(position + 1..).ok_or(DecodeError::Data)?;
+            if data.len() < stride * height { return Err(DecodeError::Data); }
+            for y in 0..height {
+                for x in 0..width {
+                    if data[y * stride + x / 8] & (0x80 >> (x % 8)) != 0 { bitmap.set_pixel(x, y, true); }
+                }
+            }
+        } else {
+            for index in 0..width * height {
+                _skip_pbm_space(pbm, &mut position);
+                match pbm.get(position) {
+                    Some(b'0') => { }
+                    Some(b'1') => { bitmap.set_pixel(index % width, index / width, true); }
+                    _ => { return Err(DecodeError::Data); }
+                }
+                position += 1;
+            }
+        }
+        return Ok(bitmap);
+    }
+
+    /// Decode an X bitmap, the C source code with `#define`s for width and
+    /// height and an array of bytes. Pixels stored as `1` are set.
+    ///
+    /// ```
+    /// let xbm = "\
+    /// #define icon_width 4
+    /// #define icon_height 2
+    /// static unsigned char icon_bits[] = { 0x06, 0x09 };
+    /// ";
+    /// let bitmap = bitmap_writer::BitmapBuf::from_xbm(xbm).unwrap();
+    /// assert_eq!((bitmap.width(), bitmap.height()), (4, 2));
+    /// assert!(bitmap.pixel(1, 0) && bitmap.pixel(0, 1));
+    /// ```
+    ///
+    /// # Arguments
+    /// - `xbm`: The content of the XBM file.
+    ///
+    /// # Returns
+    /// - The bitmap, or why the file could not be decoded.
+    pub fn from_xbm(xbm: &str) -> Result<BitmapBuf, DecodeError> {
+        let (mut width, mut height) = (None, None);
+        for line in xbm.lines() {
+            let mut words = line.split_whitespace();
+            if words.next() != Some("#define") { continue; }
+            let (Some(name), Some(value)) = (words.next(), words.next()) else { continue; };
+            if name.ends_with("_width") { width = value.parse::<usize>().ok(); }
+            if name.ends_with("_height") { height = value.parse::<usize>().ok(); }
+        }
+        let (Some(width), Some(height)) = (width, height) else { return Err(DecodeError::Signature); };
+        width.checked_mul(height).ok_or(DecodeError::Header)?;
+
+        let start = xbm.find('{').ok_or(DecodeError::Data)?;
+        let end = xbm[start..].find('}').ok_or(DecodeError::Data)? + start;
+        let stride = width.div_ceil(8);
+        let values = xbm[start + 1..end].split(',').map(str::trim).filter(|v| !v.is_empty());
+        if values.clone().count() < stride * height { return Err(DecodeError::Data); }
+
+        let mut bitmap = BitmapBuf::new(width, height);
+        for (index, value) in values.enumerate() {
+            let hex = value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")).ok_or(DecodeError::Data)?;
+            let byte = u8::from_str_radix(hex, 16).map_err(|_| DecodeError::Data)?;
+            for bit in 0..8 {
+                let (x, y) = (index % stride * 8 + bit, index / stride);
+                if x < width && y < height && byte & (1 << bit) != 0 { bitmap.set_pixel(x, y, true); }
+            }
+        }
+        return Ok(bitmap);
+    }
+
+    /// Decode an uncompressed Windows bitmap with 1, 4, 8, 24 or 32 bits per
+    /// pixel, setting the pixels darker than a threshold.
+    ///
+    /// The brightness of a pixel is the luminance of its color, see
+    /// `from_png(..)`. The channels of 32-bit pixels are read through the
+    /// color masks of the header, if given.
+    ///
+    /// ```
+    /// let bmp = [
+    ///     // File header: signature, file size, reserved, offset of the pixels.
+    ///     b'B', b'M', 70, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0,
+    ///     // Info header: size, width 4, height 2, planes, 1 bit per pixel,
+    ///     // no compression, image size, resolution, 2 colors, 0 important.
+    ///     40, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 8, 0, 0, 0,
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+    ///     // Palette: black and white.
+    ///     0, 0, 0, 0, 255, 255, 255, 0,
+    ///     // Rows padded to 4 bytes, from the bottom up.
+    ///     0b01100000, 0, 0, 0,
+    ///     0b10010000, 0, 0, 0
+    /// ];
+    /// let bitmap = bitmap_writer::BitmapBuf::from_bmp(&bmp, 128, false).unwrap();
+    /// assert_eq!((bitmap.width(), bitmap.height()), (4, 2));
+    /// assert!(bitmap.pixel(1, 0) && bitmap.pixel(0, 1) && !bitmap.pixel(0, 0));
+    ///
+    /// let bmp = [
+    ///     b'B', b'M', 74, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0,
+    ///     // Width 2, height 1, 32 bits per pixel, bit fields.
+    ///     40, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 1, 0, 32, 0, 3, 0, 0, 0, 8, 0, 0, 0,
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ///     // Red, green and blue masks of RGBA pixels.
+    ///     0xff, 0, 0, 0, 0, 0xff, 0, 0, 0, 0, 0xff, 0,
+    ///     // A red and a blue pixel.
+    ///     0xff, 0, 0, 0xff, 0, 0, 0xff, 0xff
+    /// ];
+    /// let bitmap = bitmap_writer::BitmapBuf::from_bmp(&bmp, 50, false).unwrap();
+    /// assert!(!bitmap.pixel(0, 0) && bitmap.pixel(1, 0));
+    /// ```
+    ///
+    /// # Arguments
+    /// - `bmp`: The content of the BMP file.
+    /// - `threshold`: Pixels with a brightness below are set, 0 to 255.
+    /// - `invert`: Set the pixels at or above the threshold instead, for images of lit pixels on a dark background.
+    ///
+    /// # Returns
+    /// - The bitmap, or why the file could not be decoded.
+    pub fn from_bmp(bmp: &[u8], threshold: u8, invert: bool) -> Result<BitmapBuf, DecodeError> {
+        if bmp.get(..2) != Some(b"BM") { return Err(DecodeError::Signature); }
+        let u16_at = |offset: usize| bmp.get(offset..offset + 2).map(|v| u16::from_le_bytes([v[0], v[1]]) as usize).ok_or(DecodeError::Header);
+        let u32_at = |offset: usize| bmp.get(offset..offset + 4).map(|v| u32::from_le_bytes([v[0], v[1], v[2], v[3]])).ok_or(DecodeError::Header);
+
+        let offset = u32_at(10)? as usize;
+        let header = u32_at(14)? as usize;
+        let (width, height, top_down, depth, compression, colors, entry) = if header == 12 {
+            (u16_at(18)?, u16_at(20)?, false, u16_at(24)?, 0, 0, 3)
+        } else {
+            let (width, height) = (u32_at(18)? as i32, u32_at(22)? as i32);
+            if width < 0 { return Err(DecodeError::Header); }
+            (width as usize, height.unsigned_abs() as usize, height < 0, u16_at(28)?, u32_at(30)?, u32_at(46)? as usize, 4)
+        };
+        if !matches!(depth, 1 | 4 | 8 | 24 | 32) || !(compression == 0 || (compression == 3 && depth == 32)) {
+            return Err(DecodeError::Header);
+        }
+        width.checked_mul(height).and_then(|pixels| pixels.checked_mul(depth)).ok_or(DecodeError::Header)?;
+
+        let palette_start = 14 + header;
+        let colors = if depth > 8 { 0 } else if colors == 0 { 1 << depth } else { colors };
+        let palette = bmp.get(palette_start..palette_start + colors * entry).ok_or(DecodeError::Header)?;
+
+        let stride = (width * depth).div_ceil(32) * 4;
+        let data = bmp.get(offset..).ok_or(DecodeError::Data)?;
+        if data.len() < stride * height { return Err(DecodeError::Data); }
+
+        let masks = if compression == 3 { [u32_at(54)?, u32_at(58)?, u32_at(62)?] } else { [0xff0000, 0xff00, 0xff] };
+        if masks.contains(&0) { return Err(DecodeError::Header); }
+
+        let mut bitmap = BitmapBuf::new(width, height);
+        for row in 0..height {
+            let line = &data[row * stride..(row + 1) * stride];
+            let y = if top_down { row } else { height - 1 - row };
+            for x in 0..width {
+                let (b, g, r) = match depth {
+                    24 => (line[x * 3], line[x * 3 + 1], line[x * 3 + 2]),
+                    32 => {
+                        let pixel = u32::from_le_bytes([line[x * 4], line[x * 4 + 1], line[x * 4 + 2], line[x * 4 + 3]]);
+                        let [r, g, b] = masks.map(|mask| _channel(pixel, mask));
+                        (b, g, r)
+                    }
+                    _ => {
+                        let bit = x * depth;
+                        let index = (line[bit / 8] >> (8 - depth - bit % 8)) as usize & ((1 << depth) - 1);
+                        let color = palette.get(index * entry..index * entry + 3).unwrap_or(&[0, 0, 0]);
+                        (color[0], color[1], color[2])
+                    }
+                };
+                if (_luminance(r, g, b) < threshold) != invert { bitmap.set_pixel(x, y, true); }
+            }
+        }
+        return Ok(bitmap);
+    }
+}
+
+/// Value of the color channel selected by a mask, scaled to 0 to 255.
+fn _channel(pixel: u32, mask: u32) -> u8 {
+    let shift = mask.trailing_zeros();
+    let max = (mask >> shift) as u64;
+    return (((pixel & mask) >> shift) as u64 * 255 / max) as u8;
+}
+
+/// Brightness of a color from 0 to 255.
+pub(crate) fn _luminance(r: u8, g: u8, b: u8) -> u8 {
+    return ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8;
+}
+
+/// Skip whitespace and comments in the header or plain text data of a PBM file.
+fn _skip_pbm_space(pbm: &[u8], position: &mut usize) {
+    while let Some(c) = pbm.get(*position) {
+        if *c == b'#' {
+            while pbm.get(*position).is_some_and(|c| *c != b'\n') { *position += 1; }
+        } else if c.is_ascii_whitespace() {
+            *position += 1;
+        } else {
+            return;
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::Style;
+use crate::bitmap::Bitmap;
+use crate::sink::{Sink, _write_to};
+use crate::writer::{Write, WriteResult, Writer};
+
+/// Number and position of the pixels that differ between two bitmaps.
+///
+/// Positions are in pixels as the writer sees them, after rotation and
+/// scaling, see `Writer::to_bitmap(..)`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DiffSummary {
+    /// Pixels set in the first bitmap only.
+    pub only_a: usize,
+    /// Pixels set in the second bitmap only.
+    pub only_b: usize,
+    /// Pixels set in both bitmaps.
+    pub both: usize,
+    /// Smallest rectangle `(x, y, width, height)` containing all differing
+    /// pixels, `None` if no pixels differ.
+    pub bounds: Option<(usize, usize, usize, usize)>,
+    /// Pixel size `(width, height)` of the first bitmap.
+    pub size_a: (usize, usize),
+    /// Pixel size `(width, height)` of the second bitmap.
+    pub size_b: (usize, usize)
+}
+
+impl DiffSummary {
+    /// Number of pixels that differ.
+    pub fn count(&self) -> usize {
+        return self.only_a + self.only_b;
+    }
+
+    /// Whether both bitmaps have the same size.
+    pub fn same_size(&self) -> bool {
+        return self.size_a == self.size_b;
+    }
+}
+
+impl core::fmt::Display for DiffSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.bounds {
+            Some((x, y, width, height)) => write!(
+                f, "{} pixels differ ({} only in A, {} only in B) within {}x{} at {},{}",
+                self.count(), self.only_a, self.only_b, width, height, x, y
+            )?,
+            None => write!(f, "no pixels differ")?
+        }
+        if !self.same_size() {
+            write!(f, ", sizes differ: {}x{} in A, {}x{} in B", self.size_a.0, self.size_a.1, self.size_b.0, self.size_b.1)?;
+        }
+        return Ok(());
+    }
+}
+
+/// Write two bitmaps of the same size on top of each other, highlighting
+/// the pixels that differ, followed by a `DiffSummary`.
+///
+/// The bitmaps are written with the style, frame and every other setting of
+/// the given writer. Character cells containing pixels set only in the first
+/// bitmap are colored red, only in the second bitmap green, and both kinds
+/// yellow. Without colors, such cells are written with the glyphs `-`, `+`
+/// and `*` instead. Cells without differences are written as usual.
+///
+/// Bitmaps of different sizes are compared and written over the area
+/// covering both, with the pixels outside of the smaller bitmap unset. The
+/// summary reports the mismatch.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, Diff, Style, Writer};
+///
+/// let expected = Bitmap::new(8, 2, &[0b11110000, 0b00001111]);
+/// let actual = Bitmap::new(8, 2, &[0b11110000, 0b00011110]);
+///
+/// let mut w = Writer::new();
+/// w.style(Style::ASCII1x1('#'));
+/// let mut diff = Diff::new(&w, &expected, &actual);
+/// diff.colors(false);
+/// assert_eq!(diff.summary().count(), 2);
+/// assert_eq!(diff.summary().bounds, Some((3, 1, 5, 1)));
+///
+/// let wider = Bitmap::new(10, 2, &[0b11110000, 0b11000011, 0b11000000]);
+/// let diff = Diff::new(&w, &expected, &wider);
+/// assert!(!diff.summary().same_size());
+/// assert_eq!(
+///     diff.summary().to_string(),
+///     "2 pixels differ (0 only in A, 2 only in B) within 2x1 at 8,0, sizes differ: 8x2 in A, 10x2 in B"
+/// );
+/// ```
+pub struct Diff<'a> {
+    _writer: &'a Writer<'a>,
+    _a: &'a Bitmap<'a>,
+    _b: &'a Bitmap<'a>,
+    _colors: bool,
+    _glyphs: [char; 3]
+}
+
+/// Second bitmap and highlighting attached to a writer by `Diff`.
+#[derive(Clone, Copy)]
+pub(crate) struct Compare<'a> {
+    pub(crate) _bitmap: &'a Bitmap<'a>,
+    _colors: bool,
+    _glyphs: [char; 3]
+}
+
+impl<'a> Diff<'a> {
+    /// Construct a diff of two bitmaps.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to write and read the bitmaps.
+    /// - `a`: The first bitmap, for example the expected image.
+    /// - `b`: The second bitmap, for example the actual image.
+    ///
+    /// # Returns
+    /// - Diff instance.
+    pub fn new(writer: &'a Writer<'a>, a: &'a Bitmap<'a>, b: &'a Bitmap<'a>) -> Diff<'a> {
+        return Diff { _writer: writer, _a: a, _b: b, _colors: true, _glyphs: ['-', '+', '*'] };
+    }
+
+    /// Highlight differences with ANSI colors, or with glyphs otherwise.
+    ///
+    /// # Arguments
+    /// - `state`: `true` for colors, which is the default.
+    ///
+    /// # Returns
+    /// - Reference to diff.
+    pub fn colors(&mut self, state: bool) -> &mut Self {
+        self._colors = state;
+        return self;
+    }
+
+    /// Set the glyphs that mark differing character cells without colors.
+    ///
+    /// # Arguments
+    /// - `only_a`: Cells with pixels set only in the first bitmap.
+    /// - `only_b`: Cells with pixels set only in the second bitmap.
+    /// - `mixed`: Cells with both kinds of differences.
+    ///
+    /// # Returns
+    /// - Reference to diff.
+    pub fn glyphs(&mut self, only_a: char, only_b: char, mixed: char) -> &mut Self {
+        self._glyphs = [only_a, only_b, mixed];
+        return self;
+    }
+
+    /// Count the differing pixels within the part of the bitmaps written.
+    ///
+    /// # Returns
+    /// - DiffSummary instance.
+    pub fn summary(&self) -> DiffSummary {
+        let (left, top, width, height) = self._compare_writer()._region(self._a);
+        let (size_a, size_b) = (self._writer._size(self._a), self._writer._size(self._b));
+        let mut summary = DiffSummary { only_a: 0, only_b: 0, both: 0, bounds: None, size_a, size_b };
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (usize::MAX, usize::MAX, 0, 0);
+        for y in top..top + height {
+            for x in left..left + width {
+                match (self._writer._sample(self._a, x, y), self._writer._sample(self._b, x, y)) {
+                    (true, true) => { summary.both += 1; continue; }
+                    (true, false) => { summary.only_a += 1; }
+                    (false, true) => { summary.only_b += 1; }
+                    (false, false) => { continue; }
+                }
+                (min_x, min_y, max_x, max_y) = (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y));
+            }
+        }
+        if summary.count() > 0 {
+            summary.bounds = Some((min_x, min_y, max_x - min_x + 1, max_y - min_y + 1));
+        }
+        return summary;
+    }
+
+    /// Write the combined bitmap and the summary.
+    ///
+    /// # Arguments
+    /// - `writer`: A sink implementing `core::fmt::Write` or `std::io::Write`, see `bitmap_writer::Sink`. It will contain the result of the conversion.
+    pub fn write<K>(&self, writer: &mut impl Sink<K>) {
+        _write_to(writer, |w| self._write_diff(w));
+    }
+
+    pub(crate) fn _write_diff(&self, w: &mut impl Write) -> WriteResult {
+        self._compare_writer()._write_bitmap(w, self._a)?;
+        writeln!(w, "{}", self.summary())?;
+        return Ok(());
+    }
+
+    /// The writer with the second bitmap attached, writing the area covering
+    /// both bitmaps.
+    fn _compare_writer(&self) -> Writer<'a> {
+        let mut writer = self._writer.clone();
+        writer._compare = Some(Compare { _bitmap: self._b, _colors: self._colors, _glyphs: self._glyphs });
+        return writer;
+    }
+}
+
+impl Compare<'_> {
+    /// Write a character cell given the pixels of both bitmaps as block
+    /// indices, see `Style::_glyph(..)`.
+    pub(crate) fn _write_cell(&self, w: &mut impl Write, style: Style, a: usize, b: usize) -> WriteResult {
+        let kind = match (a & !b != 0, b & !a != 0) {
+            (false, false) => { return write!(w, "{}", style._glyph(a)); }
+            (true, false) => 0,
+            (false, true) => 1,
+            (true, true) => 2
+        };
+        if !self._colors {
+            return write!(w, "{}", self._glyphs[kind]);
+        }
+        return write!(w, "\x1b[{}m{}\x1b[0m", [31, 32, 33][kind], style._glyph(a | b));
+    }
+}
+
+#[cfg(feature = "std")]
+use crate::sink::_print_to;
+
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+impl Diff<'_> {
+    /// Print the combined bitmap and the summary out to terminal on **stdout**,
+    /// ignoring errors.
+    pub fn print(&self) {
+        let _ = self.print_to(&mut std::io::stdout().lock());
+    }
+
+    /// Print the combined bitmap and the summary to any stream, see
+    /// `Writer::print_to(..)`.
+    ///
+    /// # Arguments
+    /// - `stream`: The stream to write to.
+    ///
+    /// # Returns
+    /// - The error of the stream, apart from a broken pipe.
+    pub fn print_to(&self, stream: &mut impl std::io::Write) -> std::io::Result<()> {
+        return _print_to(stream, |w| self._write_diff(w));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::bitmap::Bitmap;
+use crate::scan::Scan;
+use crate::writer::Writer;
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// A region of a file read into memory, for example a framebuffer inside a
+/// firmware image, a `/dev/fb*` device or a memory dump.
+///
+/// Only the requested bytes are read, by seeking to the offset, so regions
+/// of large files and devices are cheap to access.
+///
+/// ```no_run
+/// use bitmap_writer::{FileRegion, Writer};
+///
+/// let mut w = Writer::new();
+/// w.byte_aligned(true);
+/// let region = FileRegion::read("firmware.bin", 0x1f400, Some(w.data_len(128, 64))).unwrap();
+/// w.print(&region.bitmap(128, 64));
+/// ```
+///
+/// Requires `features=["std"]` in ```cargo.toml```.
+pub struct FileRegion {
+    _data: Vec<u8>,
+    _offset: u64
+}
+
+impl FileRegion {
+    /// Read a region of a file.
+    ///
+    /// Regions reaching past the end of the file are cut short, so the data
+    /// may be shorter than requested, or empty if the offset is past the
+    /// end. Pixels without data are unset when written.
+    ///
+    /// # Arguments
+    /// - `path`: Path of the file or device.
+    /// - `offset`: Offset of the region in bytes.
+    /// - `length`: Length of the region in bytes, `None` to read up to the end of the file.
+    ///
+    /// # Returns
+    /// - The region, or the error opening, seeking or reading the file.
+    pub fn read(path: impl AsRef<Path>, offset: u64, length: Option<usize>) -> std::io::Result<FileRegion> {
+        let mut file = File::open(path)?;
+        if offset > 0 { file.seek(SeekFrom::Start(offset))?; }
+        let mut data = Vec::new();
+        match length {
+            Some(length) => { file.take(length as u64).read_to_end(&mut data)?; }
+            None => { file.read_to_end(&mut data)?; }
+        }
+        return Ok(FileRegion { _data: data, _offset: offset });
+    }
+
+    /// Get the offset the region was read from.
+    ///
+    /// # Returns
+    /// - Offset in the file in bytes.
+    pub fn offset(&self) -> u64 {
+        return self._offset;
+    }
+
+    /// Get the bytes read.
+    ///
+    /// # Returns
+    /// - The data of the region.
+    pub fn data(&self) -> &[u8] {
+        return &self._data;
+    }
+
+    /// Interpret the start of the region as a bitmap.
+    ///
+    /// # Arguments
+    /// - `width`: Pixel width.
+    /// - `height`: Pixel height.
+    ///
+    /// # Returns
+    /// - Bitmap instance borrowing the region's data.
+    pub fn bitmap(&self, width: usize, height: usize) -> Bitmap<'_> {
+        return Bitmap::new(width, height, &self._data);
+    }
+
+    /// Scan the region for bitmaps at successive offsets, which are written
+    /// as offsets into the file.
+    ///
+    /// ```no_run
+    /// use bitmap_writer::{FileRegion, Writer};
+    ///
+    /// let w = Writer::new();
+    /// let region = FileRegion::read("dump.bin", 0x8000, Some(0x4000)).unwrap();
+    /// region.scan(&w, 128, 32).step(Some(16)).print();
+    /// ```
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to read and write each bitmap.
+    /// - `width`: Pixel width of each bitmap.
+    /// - `height`: Pixel height of each bitmap.
+    ///
+    /// # Returns
+    /// - Scan instance, see `bitmap_writer::Scan`.
+    pub fn scan<'a>(&'a self, writer: &'a Writer<'a>, width: usize, height: usize) -> Scan<'a> {
+        let mut scan = Scan::new(writer, &self._data, width, height);
+        scan.base(self._offset);
+        return scan;
+    }
+}
@@ -0,0 +1,281 @@
+use crate::Diff;
+use crate::bitmap::Bitmap;
+use crate::sink::{Sink, _write_to};
+use crate::writer::{Write, WriteResult, Writer};
+
+/// Colors of the 16 standard ANSI colors, normal followed by bright.
+const PALETTE: [&str; 16] = [
+    "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+    "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff"
+];
+
+/// What an `Html` instance writes.
+enum Source<'a> {
+    Bitmap(&'a Writer<'a>, &'a Bitmap<'a>),
+    Diff(&'a Diff<'a>)
+}
+
+/// Write a bitmap as HTML, for reports viewed in a browser.
+///
+/// The text written by the writer, including style, frame and every other
+/// setting, is wrapped into a `<pre>` element. Its CSS pins `line-height`
+/// and `letter-spacing` so block and sextant glyphs of neighbouring
+/// characters touch like they do in a terminal. `<`, `>` and `&` are
+/// escaped, and ANSI colors, as written by `Diff`, are translated into
+/// `<span>` elements. Other ANSI commands, like positioning, are removed.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, Html, Style, Writer};
+///
+/// let bitmap = Bitmap::new(8, 2, &[0b11110000, 0b00001111]);
+/// let mut w = Writer::new();
+/// w.style(Style::UnicodeBlock2x2);
+/// let mut html = Html::new(&w, &bitmap);
+/// html.font("'Cascadia Mono', monospace").ansi_colors(true);
+///
+/// let mut text = String::new();
+/// html.write(&mut text);
+/// assert!(text.starts_with("<pre style=\"font-family: 'Cascadia Mono', monospace;"));
+/// assert!(text.ends_with("\">▀▀▄▄\n</pre>\n"));
+/// ```
+///
+/// The output of a `Diff` with a title, a footer and ANSI positioning:
+///
+/// ```
+/// use bitmap_writer::{Align, Bitmap, Diff, Frame, Html, Style, Writer};
+///
+/// let a = Bitmap::new(8, 1, &[0b11110000]);
+/// let b = Bitmap::new(8, 1, &[0b11001100]);
+/// let mut w = Writer::new();
+/// w.style(Style::ASCII1x1('#'))
+///     .frame(Frame::ASCIIFrame)
+///     .title("<b>", Align::Left)
+///     .footer("&", Align::Right)
+///     .ansi_position(2, 3);
+/// let diff = Diff::new(&w, &a, &b);
+///
+/// let mut text = String::new();
+/// Html::diff(&diff).font("mono").write(&mut text);
+/// let (style, body) = text.split_once("\">").unwrap();
+/// assert!(style.starts_with("<pre style=\"font-family: mono;"));
+/// assert_eq!(body, "\
+/// .- &lt;b&gt; --.
+/// |##<span style=\"color: #cd0000;\">#</span><span style=\"color: #cd0000;\">#</span>\
+/// <span style=\"color: #00cd00;\">#</span><span style=\"color: #00cd00;\">#</span>  |
+/// '---- &amp; -'
+/// 4 pixels differ (2 only in A, 2 only in B) within 4x1 at 2,0
+/// </pre>
+/// ");
+///
+/// let mut text = String::new();
+/// Html::diff(&diff).ansi_colors(false).write(&mut text);
+/// assert!(text.contains("\">.- &lt;b&gt; --.\n|######  |\n'---- &amp; -'\n"));
+/// ```
+pub struct Html<'a> {
+    _source: Source<'a>,
+    _font: &'a str,
+    _ansi_colors: bool
+}
+
+impl<'a> Html<'a> {
+    /// Construct an HTML writer for a bitmap.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to write the bitmap.
+    /// - `bitmap`: The bitmap to write.
+    ///
+    /// # Returns
+    /// - Html instance.
+    pub fn new(writer: &'a Writer<'a>, bitmap: &'a Bitmap<'a>) -> Html<'a> {
+        return Html { _source: Source::Bitmap(writer, bitmap), _font: "'DejaVu Sans Mono', 'Cascadia Mono', monospace", _ansi_colors: true };
+    }
+
+    /// Construct an HTML writer for the output of a `Diff`, including its
+    /// colors and summary.
+    ///
+    /// # Arguments
+    /// - `diff`: The diff to write.
+    ///
+    /// # Returns
+    /// - Html instance.
+    pub fn diff(diff: &'a Diff<'a>) -> Html<'a> {
+        return Html { _source: Source::Diff(diff), _font: "'DejaVu Sans Mono', 'Cascadia Mono', monospace", _ansi_colors: true };
+    }
+
+    /// Set the CSS `font-family`. The font should contain the glyphs of the
+    /// writer's style and frame, fonts without them may fall back to glyphs
+    /// of a different width.
+    ///
+    /// # Arguments
+    /// - `font`: CSS font-family list.
+    ///
+    /// # Returns
+    /// - Reference to html.
+    pub fn font(&mut self, font: &'a str) -> &mut Self {
+        self._font = font;
+        return self;
+    }
+
+    /// Translate ANSI colors into `<span>` elements, or remove them.
+    ///
+    /// # Arguments
+    /// - `state`: `true` to translate, which is the default.
+    ///
+    /// # Returns
+    /// - Reference to html.
+    pub fn ansi_colors(&mut self, state: bool) -> &mut Self {
+        self._ansi_colors = state;
+        return self;
+    }
+
+    /// Write the `<pre>` element.
+    ///
+    /// # Arguments
+    /// - `writer`: A sink implementing `core::fmt::Write` or `std::io::Write`, see `bitmap_writer::Sink`. It will contain the resulting HTML.
+    pub fn write<K>(&self, writer: &mut impl Sink<K>) {
+        _write_to(writer, |w| self._write_html(w));
+    }
+
+    fn _write_html(&self, w: &mut impl Write) -> WriteResult {
+        write!(w, "<pre style=\"font-family: ")?;
+        for c in self._font.chars() {
+            if c == '"' { write!(w, "&quot;")?; } else { write!(w, "{}", c)?; }
+        }
+        write!(w, "; font-size: 16px; line-height: 1; letter-spacing: 0; font-kerning: none; font-variant-ligatures: none; white-space: pre;\">")?;
+
+        let mut escape = HtmlEscape::new(w, self._ansi_colors);
+        match self._source {
+            Source::Bitmap(writer, bitmap) => { writer._write_bitmap(&mut escape, bitmap)?; }
+            Source::Diff(diff) => { diff._write_diff(&mut escape)?; }
+        }
+        escape._close_span()?;
+        writeln!(w, "</pre>")?;
+        return Ok(());
+    }
+}
+
+/// Sink that escapes HTML and translates ANSI commands before passing text
+/// on to another sink.
+struct HtmlEscape<'w, W: Write> {
+    _inner: &'w mut W,
+    _ansi_colors: bool,
+    _state: u8,
+    _sequence: [u8; 32],
+    _length: usize,
+    _foreground: Option<usize>,
+    _background: Option<usize>,
+    _bold: bool,
+    _open: bool
+}
+
+impl<'w, W: Write> HtmlEscape<'w, W> {
+    fn new(inner: &'w mut W, ansi_colors: bool) -> HtmlEscape<'w, W> {
+        return HtmlEscape {
+            _inner: inner, _ansi_colors: ansi_colors, _state: 0, _sequence: [0; 32], _length: 0,
+            _foreground: None, _background: None, _bold: false, _open: false
+        };
+    }
+
+    /// Process text, which may end or start in the middle of an ANSI command.
+    fn _feed(&mut self, bytes: &[u8]) -> WriteResult {
+        let mut start = 0;
+        for (i, byte) in bytes.iter().enumerate() {
+            if self._state == 0 && !matches!(byte, b'<' | b'>' | b'&' | 0x1b) { continue; }
+            self._raw(&bytes[start..i])?;
+            start = i + 1;
+            match (self._state, byte) {
+                (0, b'<') => { write!(self._inner, "&lt;")?; }
+                (0, b'>') => { write!(self._inner, "&gt;")?; }
+                (0, b'&') => { write!(self._inner, "&amp;")?; }
+                (0, _) => { self._state = 1; }
+                (1, b'[') => { self._state = 2; self._length = 0; }
+                (1, _) => { self._state = 0; }
+                (_, 0x40..=0x7e) => {
+                    self._state = 0;
+                    if *byte == b'm' { self._select_graphic_rendition()?; }
+                }
+                _ => {
+                    if self._length < self._sequence.len() { self._sequence[self._length] = *byte; }
+                    self._length += 1;
+                }
+            }
+        }
+        if self._state == 0 { self._raw(&bytes[start..])?; }
+        return Ok(());
+    }
+
+    /// Apply the parameters of an ANSI color command and reopen the span.
+    fn _select_graphic_rendition(&mut self) -> WriteResult {
+        if !self._ansi_colors { return Ok(()); }
+        let length = self._length.min(self._sequence.len());
+        let parameters = core::str::from_utf8(&self._sequence[..length]).unwrap_or("");
+        for parameter in parameters.split(';') {
+            match parameter.parse::<usize>().unwrap_or(0) {
+                0 => { (self._foreground, self._background, self._bold) = (None, None, false); }
+                1 => { self._bold = true; }
+                22 => { self._bold = false; }
+                value @ 30..=37 => { self._foreground = Some(value - 30); }
+                39 => { self._foreground = None; }
+                value @ 40..=47 => { self._background = Some(value - 40); }
+                49 => { self._background = None; }
+                value @ 90..=97 => { self._foreground = Some(value - 90 + 8); }
+                value @ 100..=107 => { self._background = Some(value - 100 + 8); }
+                _ => { }
+            }
+        }
+
+        self._close_span()?;
+        if self._foreground.is_none() && self._background.is_none() && !self._bold { return Ok(()); }
+        write!(self._inner, "<span style=\"")?;
+        if let Some(color) = self._foreground { write!(self._inner, "color: {};", PALETTE[color])?; }
+        if let Some(color) = self._background { write!(self._inner, "background-color: {};", PALETTE[color])?; }
+        if self._bold { write!(self._inner, "font-weight: bold;")?; }
+        write!(self._inner, "\">")?;
+        self._open = true;
+        return Ok(());
+    }
+
+    fn _close_span(&mut self) -> WriteResult {
+        if self._open {
+            write!(self._inner, "</span>")?;
+            self._open = false;
+        }
+        return Ok(());
+    }
+
+    /// Pass text on unchanged. It never starts or ends within a UTF-8
+    /// character, as it is only split at ASCII characters.
+    fn _raw(&mut self, bytes: &[u8]) -> WriteResult {
+        if bytes.is_empty() { return Ok(()); }
+        return self._inner.write_str(core::str::from_utf8(bytes).unwrap_or(""));
+    }
+}
+
+impl<W: Write> core::fmt::Write for HtmlEscape<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        return self._feed(s.as_bytes());
+    }
+}
+
+#[cfg(feature = "std")]
+use crate::sink::_print_to;
+
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+impl Html<'_> {
+    /// Print the HTML out to terminal on **stdout**, ignoring errors.
+    pub fn print(&self) {
+        let _ = self.print_to(&mut std::io::stdout().lock());
+    }
+
+    /// Print the HTML to any stream, see `Writer::print_to(..)`.
+    ///
+    /// # Arguments
+    /// - `stream`: The stream to write to.
+    ///
+    /// # Returns
+    /// - The error of the stream, apart from a broken pipe.
+    pub fn print_to(&self, stream: &mut impl std::io::Write) -> std::io::Result<()> {
+        return _print_to(stream, |w| self._write_html(w));
+    }
+}
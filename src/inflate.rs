@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+
+/// Base lengths of the length symbols 257 to 285.
+const LENGTH_BASE: [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+/// Extra bits of the length symbols 257 to 285.
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+/// Base distances of the distance symbols 0 to 29.
+const DISTANCE_BASE: [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+/// Extra bits of the distance symbols 0 to 29.
+const DISTANCE_EXTRA: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+/// Order in which the code length code lengths of a dynamic block are stored.
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+/// Reads a deflate stream bit by bit, least significant bit first.
+struct BitReader<'a> {
+    _data: &'a [u8],
+    _position: usize,
+    _buffer: u32,
+    _count: u32
+}
+
+impl BitReader<'_> {
+    fn _bits(&mut self, count: u32) -> Option<u32> {
+        while self._count < count {
+            let byte = *self._data.get(self._position)?;
+            self._position += 1;
+            self._buffer |= (byte as u32) << self._count;
+            self._count += 8;
+        }
+        let value = self._buffer & ((1u32 << count) - 1);
+        self._buffer >>= count;
+        self._count -= count;
+        return Some(value);
+    }
+
+    /// Skip to the next byte boundary.
+    fn _align(&mut self) {
+        self._buffer = 0;
+        self._count = 0;
+    }
+}
+
+/// Canonical Huffman code, as number of codes per length and the symbols
+/// ordered by code.
+struct Huffman {
+    _counts: [u16; 16],
+    _symbols: Vec<u16>
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Option<Huffman> {
+        let mut counts = [0u16; 16];
+        for length in lengths { counts[*length as usize] += 1; }
+        counts[0] = 0;
+
+        let mut left: i32 = 1;
+        for count in &counts[1..] {
+            left = left * 2 - *count as i32;
+            if left < 0 { return None; }
+        }
+
+        let mut offsets = [0u16; 16];
+        for length in 1..15 { offsets[length + 1] = offsets[length] + counts[length]; }
+        let mut symbols = alloc::vec![0u16; lengths.len()];
+        for (symbol, length) in lengths.iter().enumerate() {
+            if *length != 0 {
+                symbols[offsets[*length as usize] as usize] = symbol as u16;
+                offsets[*length as usize] += 1;
+            }
+        }
+        return Some(Huffman { _counts: counts, _symbols: symbols });
+    }
+
+    fn _decode(&self, reader: &mut BitReader) -> Option<u16> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for length in 1..16 {
+            code |= reader._bits(1)? as i32;
+            let count = self._counts[length] as i32;
+            if code - count < first {
+                return self._symbols.get((index + code - first) as usize).copied();
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        return None;
+    }
+}
+
+/// Decompress a zlib stream, verifying its checksum.
+///
+/// # Returns
+/// - The decompressed data, `None` if the stream is invalid.
+pub(crate) fn _zlib_decompress(data: &[u8]) -> Option<Vec<u8>> {
+    let (cmf, flg) = (*data.first()?, *data.get(1)?);
+    if cmf & 0x0f != 8 || !((cmf as u16) << 8 | flg as u16).is_multiple_of(31) || flg & 0x20 != 0 { return None; }
+    let mut reader = BitReader { _data: data, _position: 2, _buffer: 0, _count: 0 };
+    let output = _inflate(&mut reader)?;
+    let checksum = data.get(reader._position..reader._position + 4)?;
+    if u32::from_be_bytes([checksum[0], checksum[1], checksum[2], checksum[3]]) != crate::png::_adler32(&output) { return None; }
+    return Some(output);
+}
+
+fn _inflate(reader: &mut BitReader) -> Option<Vec<u8>> {
+    let mut output = Vec::new();
+    loop {
+        let last = reader._bits(1)? == 1;
+        match reader._bits(2)? {
+            0 => {
+                reader._align();
+                let header = reader._data.get(reader._position..reader._position + 4)?;
+                let length = u16::from_le_bytes([header[0], header[1]]);
+                if length != !u16::from_le_bytes([header[2], header[3]]) { return None; }
+                let start = reader._position + 4;
+                output.extend_from_slice(reader._data.get(start..start + length as usize)?);
+                reader._position = start + length as usize;
+            }
+            1 => {
+                let mut lengths = [0u8; 288 + 32];
+                lengths[..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..288].fill(8);
+                lengths[288..].fill(5);
+                _inflate_block(reader, &mut output, &Huffman::new(&lengths[..288])?, &Huffman::new(&lengths[288..])?)?;
+            }
+            2 => {
+                let literals = reader._bits(5)? as usize + 257;
+                let distances = reader._bits(5)? as usize + 1;
+                let code_lengths = reader._bits(4)? as usize + 4;
+
+                let mut lengths = [0u8; 19];
+                for i in 0..code_lengths { lengths[CODE_LENGTH_ORDER[i]] = reader._bits(3)? as u8; }
+                let code = Huffman::new(&lengths)?;
+
+                let mut lengths = [0u8; 288 + 32];
+                let mut i = 0;
+                while i < literals + distances {
+                    let (value, repeat) = match code._decode(reader)? {
+                        symbol @ 0..=15 => (symbol as u8, 1),
+                        16 => (*lengths.get(i.checked_sub(1)?)?, 3 + reader._bits(2)? as usize),
+                        17 => (0, 3 + reader._bits(3)? as usize),
+                        _ => (0, 11 + reader._bits(7)? as usize)
+                    };
+                    if i + repeat > literals + distances { return None; }
+                    lengths[i..i + repeat].fill(value);
+                    i += repeat;
+                }
+                _inflate_block(reader, &mut output, &Huffman::new(&lengths[..literals])?, &Huffman::new(&lengths[literals..literals + distances])?)?;
+            }
+            _ => { return None; }
+        }
+        if last { return Some(output); }
+    }
+}
+
+fn _inflate_block(reader: &mut BitReader, output: &mut Vec<u8>, literals: &Huffman, distances: &Huffman) -> Option<()> {
+    loop {
+        let symbol = literals._decode(reader)? as usize;
+        if symbol < 256 {
+            output.push(symbol as u8);
+            continue;
+        }
+        if symbol == 256 { return Some(()); }
+
+        let symbol = symbol - 257;
+        let length = *LENGTH_BASE.get(symbol)? as usize + reader._bits(LENGTH_EXTRA[symbol] as u32)? as usize;
+        let symbol = distances._decode(reader)? as usize;
+        let distance = *DISTANCE_BASE.get(symbol)? as usize + reader._bits(DISTANCE_EXTRA[symbol] as u32)? as usize;
+        let start = output.len().checked_sub(distance)?;
+        for i in 0..length { output.push(output[start + i]); }
+    }
+}
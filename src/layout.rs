@@ -0,0 +1,216 @@
+use crate::bitmap::Bitmap;
+use crate::sink::{Sink, _write_to};
+use crate::writer::{Write, WriteResult, Writer};
+
+/// A bitmap placed into a `Layout`, together with the writer used to write
+/// it and an optional caption.
+///
+/// The frame, style and every other setting of the panel's writer apply,
+/// except for the ANSI positioning which is ignored.
+pub struct Panel<'a> {
+    _writer: &'a Writer<'a>,
+    _bitmap: &'a Bitmap<'a>,
+    _caption: Option<&'a str>
+}
+
+impl<'a> Panel<'a> {
+    /// Construct a panel without caption.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to write the bitmap.
+    /// - `bitmap`: The bitmap to write.
+    ///
+    /// # Returns
+    /// - Panel instance.
+    pub fn new(writer: &'a Writer<'a>, bitmap: &'a Bitmap<'a>) -> Panel<'a> {
+        return Panel { _writer: writer, _bitmap: bitmap, _caption: None };
+    }
+
+    /// Set a caption that is written above the panel.
+    ///
+    /// Captions longer than the panel is wide are cut off.
+    ///
+    /// # Arguments
+    /// - `caption`: The caption text.
+    ///
+    /// # Returns
+    /// - Panel instance.
+    pub fn caption(mut self, caption: &'a str) -> Panel<'a> {
+        self._caption = Some(caption);
+        return self;
+    }
+}
+
+/// Write several bitmaps next to each other or in a grid.
+///
+/// By default all panels are written side-by-side in a single row.
+///
+/// Every grid column is as wide as its widest panel, shorter panels are
+/// filled with blank lines, and captions are cut off at the panel's width.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, Frame, Layout, Panel, Style, Writer};
+///
+/// let wide = Bitmap::new(8, 2, &[0b11110000, 0b00001111]);
+/// let tall = Bitmap::new(2, 4, &[0b10011001]);
+/// let framed_wide = Bitmap::new(10, 1, &[0b10101010, 0b10000000]);
+///
+/// let mut w = Writer::new();
+/// w.style(Style::ASCII1x1('o'));
+/// let mut framed = Writer::new();
+/// framed.style(Style::ASCII1x1('o')).frame(Frame::ASCIIFrame);
+/// let panels = [
+///     Panel::new(&w, &wide).caption("wide"),
+///     Panel::new(&framed, &tall).caption("tall and long"),
+///     Panel::new(&framed, &framed_wide).caption("framed"),
+/// ];
+///
+/// let mut text = String::new();
+/// Layout::new(&panels).columns(2).spacing(3, 1).write(&mut text);
+/// assert_eq!(text, "\
+/// wide           tall
+/// oooo           .--.
+///     oooo       |o |
+///                | o|
+///                |o |
+///                | o|
+///                '--'
+///
+/// framed
+/// .----------.
+/// |o o o o o |
+/// '----------'
+/// ");
+/// ```
+pub struct Layout<'a> {
+    _panels: &'a [Panel<'a>],
+    _columns: usize,
+    _spacing: usize,
+    _line_spacing: usize
+}
+
+impl<'a> Layout<'a> {
+    /// Construct a layout for a list of panels.
+    ///
+    /// # Arguments
+    /// - `panels`: The panels, written from left to right and top to bottom.
+    ///
+    /// # Returns
+    /// - Layout instance.
+    pub fn new(panels: &'a [Panel<'a>]) -> Layout<'a> {
+        return Layout { _panels: panels, _columns: panels.len(), _spacing: 2, _line_spacing: 1 };
+    }
+
+    /// Set how many panels are written next to each other before starting
+    /// the next row of the grid.
+    ///
+    /// # Arguments
+    /// - `columns`: Number of panels per row.
+    ///
+    /// # Returns
+    /// - Reference to layout.
+    pub fn columns(&mut self, columns: usize) -> &mut Self {
+        self._columns = columns;
+        return self;
+    }
+
+    /// Set the space between the panels.
+    ///
+    /// # Arguments
+    /// - `columns`: Number of characters between panels next to each other.
+    /// - `lines`: Number of empty lines between the rows of the grid.
+    ///
+    /// # Returns
+    /// - Reference to layout.
+    pub fn spacing(&mut self, columns: usize, lines: usize) -> &mut Self {
+        self._spacing = columns;
+        self._line_spacing = lines;
+        return self;
+    }
+
+    /// Write all panels.
+    ///
+    /// # Arguments
+    /// - `writer`: A sink implementing `core::fmt::Write` or `std::io::Write`, see `bitmap_writer::Sink`. It will contain the result of the conversion.
+    pub fn write<K>(&self, writer: &mut impl Sink<K>) {
+        _write_to(writer, |w| self._write_layout(w));
+    }
+
+    fn _write_layout(&self, w: &mut impl Write) -> WriteResult {
+        let columns = self._columns.max(1);
+
+        for (row, panels) in self._panels.chunks(columns).enumerate() {
+            if row > 0 {
+                for _i in 0..self._line_spacing { writeln!(w)?; }
+            }
+
+            if panels.iter().any(|p| p._caption.is_some()) {
+                for (i, panel) in panels.iter().enumerate() {
+                    let width = self._column_width(row * columns + i, columns);
+                    let caption = panel._caption.unwrap_or("");
+                    let mut used = 0;
+                    for c in caption.chars().take(width) {
+                        write!(w, "{}", c)?;
+                        used += 1;
+                    }
+                    if i + 1 < panels.len() { self._write_spaces(w, width - used + self._spacing)?; }
+                }
+                writeln!(w)?;
+            }
+
+            let lines = panels.iter().map(|p| p._writer._lines(p._bitmap)).max().unwrap_or(0);
+            for line in 0..lines {
+                for (i, panel) in panels.iter().enumerate() {
+                    let width = self._column_width(row * columns + i, columns);
+                    let mut used = 0;
+                    if line < panel._writer._lines(panel._bitmap) {
+                        panel._writer._write_line(w, panel._bitmap, line)?;
+                        used = panel._writer._columns(panel._bitmap);
+                    }
+                    if i + 1 < panels.len() { self._write_spaces(w, width - used + self._spacing)?; }
+                }
+                writeln!(w)?;
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Width of the widest panel in the same grid column as the panel.
+    fn _column_width(&self, index: usize, columns: usize) -> usize {
+        return self._panels.iter()
+            .skip(index % columns)
+            .step_by(columns)
+            .map(|p| p._writer._columns(p._bitmap))
+            .max()
+            .unwrap_or(0);
+    }
+
+    fn _write_spaces(&self, w: &mut impl Write, count: usize) -> WriteResult {
+        for _i in 0..count { write!(w, " ")?; }
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "std")]
+use crate::sink::_print_to;
+
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+impl Layout<'_> {
+    /// Print all panels out to terminal on **stdout**, ignoring errors.
+    pub fn print(&self) {
+        let _ = self.print_to(&mut std::io::stdout().lock());
+    }
+
+    /// Print all panels to any stream, see `Writer::print_to(..)`.
+    ///
+    /// # Arguments
+    /// - `stream`: The stream to write to.
+    ///
+    /// # Returns
+    /// - The error of the stream, apart from a broken pipe.
+    pub fn print_to(&self, stream: &mut impl std::io::Write) -> std::io::Result<()> {
+        return _print_to(stream, |w| self._write_layout(w));
+    }
+}
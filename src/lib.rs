@@ -1,8 +1,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::needless_return)]
 
 //! Convert monochrome pixel data to text for displaying in terminal or
 //! transfer.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 
 /// The style determines the character set used to convert the bitmap.
 /// 
@@ -27,6 +31,7 @@
 /// On the `ASCII1x1`, a style character must be provided which will be used
 /// to display the set pixels, while an unset pixel is represented by a blank
 /// space.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(usize)]
 pub enum Style {
     ASCII1x1(char) = 0,
@@ -43,6 +48,29 @@ pub enum Style {
 /// Much like with the styles, the results are depended on the font used.
 /// The `ASCII` frame should always work. The `Unicode` frames are nicer
 /// but support is more spotty.
+///
+/// The `Custom` frame uses the given characters for the corners, edges and
+/// center, from left to right and top to bottom:
+///
+/// ```
+/// use bitmap_writer::{Bitmap, Frame, Style, Writer};
+///
+/// let rounded = Frame::Custom([
+///     '╭', '─', '╮',
+///     '│', '·', '│',
+///     '╰', '─', '╯'
+/// ]);
+///
+/// // The center fills the padding.
+/// let bitmap = Bitmap::new(5, 1, &[0b10001000]);
+/// let mut w = Writer::new();
+/// w.style(Style::ASCII1x1('#')).frame(rounded).padding(0, 1, 0, 0);
+/// assert_eq!(format!("{}", w.display(&bitmap)), "╭──────╮\n│#   #·│\n╰──────╯\n");
+/// ```
+///
+/// The `Spec` frame allows edges made of multiple characters, see
+/// `bitmap_writer::FrameSpec`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[repr(usize)]
 pub enum Frame {
     NoFrame = 0,
@@ -51,12 +79,183 @@ pub enum Frame {
     UnicodeBoldFrame = 3,
     UnicodeDoubleUFrame = 4,
     UnicodeBlockFrame = 5,
-    UnicodeShadeFrame = 6
+    UnicodeShadeFrame = 6,
+    UnicodeRoundFrame = 7,
+    Custom([char; 9]) = 8,
+    Spec(FrameSpec) = 9
+}
+
+/// Characters of a `Frame::Spec` frame.
+///
+/// The edges are repeated along the sides of the frame, so an edge of
+/// multiple characters creates a pattern. Empty edges are drawn as spaces.
+///
+/// ```
+/// use bitmap_writer::{Frame, FrameSpec};
+///
+/// const DASHED: Frame = Frame::Spec(FrameSpec {
+///     corners: ['╭', '╮', '╰', '╯'],
+///     top: "─┄",
+///     bottom: "─┄",
+///     left: "│┆",
+///     right: "│┆",
+///     fill: ' '
+/// });
+///
+/// // A pattern along the top and left, a single character on the right,
+/// // and an empty bottom edge.
+/// let spec = Frame::Spec(FrameSpec { corners: ['+', '+', '+', '+'], top: "-=", bottom: "", left: "|:", right: "!", fill: '.' });
+/// let bitmap = bitmap_writer::Bitmap::new(5, 2, &[0b10001010]);
+/// let mut w = bitmap_writer::Writer::new();
+/// w.style(bitmap_writer::Style::ASCII1x1('#')).frame(spec).padding(1, 1, 0, 0);
+/// assert_eq!(format!("{}", w.display(&bitmap)), "\
+///     +-=-=-=+\n\
+///     |......!\n\
+///     :#   #.!\n\
+///     | #   .!\n\
+///     +      +\n");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FrameSpec {
+    /// Top-left, top-right, bottom-left and bottom-right corner.
+    pub corners: [char; 4],
+    pub top: &'static str,
+    pub bottom: &'static str,
+    pub left: &'static str,
+    pub right: &'static str,
+    /// Character used to fill the area inside the frame that is not covered
+    /// by the bitmap.
+    pub fill: char
+}
+
+/// Horizontal alignment of text, such as the title of a frame.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(usize)]
+pub enum Align {
+    Left = 0,
+    Center = 1,
+    Right = 2
+}
+
+/// Scaling applied to the bitmap while writing it.
+///
+/// `Up` repeats every pixel by the given factor in both directions.
+///
+/// The `Down` variants combine blocks of pixels of the given factor in both
+/// directions into one pixel:
+/// - `DownNearest` uses the top-left pixel of each block.
+/// - `DownOr` sets the pixel if any pixel of the block is set, which keeps
+///   thin lines visible.
+/// - `DownMajority` sets the pixel if more than half of the pixels of the
+///   block are set.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Scale {
+    Original,
+    Up(usize),
+    DownNearest(usize),
+    DownOr(usize),
+    DownMajority(usize)
+}
+
+/// Clockwise rotation applied to the bitmap while writing it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(usize)]
+pub enum Rotation {
+    Rotate0 = 0,
+    Rotate90 = 1,
+    Rotate180 = 2,
+    Rotate270 = 3
+}
+
+/// Programming language of the source code written by `Codegen`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(usize)]
+pub enum Language {
+    /// `const` array of `u8` with `usize` constants for width and height.
+    Rust = 0,
+    /// `const uint8_t[]` array with `#define`s for width and height.
+    C = 1
 }
 
+/// Notation of the bytes in the source code written by `Codegen`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(usize)]
+pub enum Literal {
+    /// `0b00111100`, showing the pixels. Binary literals in C require C23 or
+    /// a compiler extension.
+    Binary = 0,
+    /// `0x3c`.
+    Hex = 1
+}
 
 mod bitmap;
 pub use self::bitmap::Bitmap;
+#[cfg(feature = "alloc")]
+pub use self::bitmap::BitmapBuf;
 
 mod writer;
-pub use self::writer::Writer;
\ No newline at end of file
+pub use self::writer::Writer;
+
+mod sink;
+pub use self::sink::{Fmt, Sink};
+#[cfg(feature = "std")]
+pub use self::sink::Io;
+
+mod layout;
+pub use self::layout::{Layout, Panel};
+
+mod chars;
+
+mod measure;
+pub use self::measure::Measurement;
+
+mod source;
+pub use self::source::{Image, PixelSource, RowBuffer, RowSource};
+
+mod diff;
+pub use self::diff::{Diff, DiffSummary};
+
+mod svg;
+pub use self::svg::Svg;
+
+mod html;
+pub use self::html::Html;
+
+mod codegen;
+pub use self::codegen::Codegen;
+
+mod scan;
+pub use self::scan::Scan;
+
+#[cfg(feature = "alloc")]
+mod decode;
+#[cfg(feature = "alloc")]
+pub use self::decode::DecodeError;
+#[cfg(feature = "alloc")]
+mod png;
+#[cfg(feature = "alloc")]
+pub use self::png::Png;
+#[cfg(feature = "alloc")]
+mod inflate;
+
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "alloc")]
+pub use self::parse::ParseError;
+#[cfg(feature = "alloc")]
+mod bytes;
+#[cfg(feature = "alloc")]
+pub use self::bytes::parse_bytes;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+pub use self::file::FileRegion;
+
+#[cfg(feature = "std")]
+mod pager;
+#[cfg(feature = "std")]
+pub use self::pager::{Pager, Key};
@@ -0,0 +1,284 @@
+//! Command-line tool to print 1-bit bitmaps to the terminal or convert them.
+//!
+//! Requires `features=["cli"]` in ```cargo.toml```, run `bitmap_writer --help`
+//! for the options.
+
+#![allow(clippy::needless_return)]
+
+use bitmap_writer::{Bitmap, BitmapBuf, Codegen, FileRegion, Frame, Html, Language, Literal, Png, Scan, Style, Svg, Writer, parse_bytes};
+
+use std::io::{Read, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: bitmap_writer [OPTIONS] [FILE]
+
+Print a 1-bit bitmap to the terminal or convert it into another format.
+Reads FILE, or stdin if FILE is missing or -.
+
+Input:
+  --format FORMAT   raw, hex, pbm, xbm, bmp or png, detected from the content by default
+                    hex is text with bytes like 0x3F, 0b00111111, 3f or xxd output, used as raw data
+  --bytes TEXT      Read the input from TEXT instead of a file, e.g. pasted hex bytes
+  --width N         Width of raw data in pixels, required for raw data
+  --height N        Height of raw data in pixels, by default as many rows as the data holds
+  --be              Raw data stores the first pixel of a byte in the least significant bit
+  --aligned         Raw data starts every row of pixels with a new byte
+  --threshold N     Brightness from 0 to 255 below which BMP and PNG pixels are set, default 128
+  --invert          Set BMP and PNG pixels at or above the threshold instead
+  --offset N        Start reading at this byte of the input, default 0
+  --length N        Read at most this many bytes, by default up to the end of the input
+
+Scan:
+  --scan            Write the raw bitmaps at successive offsets of the input, each below its offset,
+                    requires --width and --height
+  --step N          Distance between the offsets in bytes, by default the length of one bitmap
+  --count N         Write at most this many bitmaps
+
+Output:
+  --output FORMAT   text, svg, html, png, rust or c, default text
+  --style STYLE     ascii, block1x1, block1x2, block2x2, sextant1x3 or sextant2x3, default block1x2
+  --frame FRAME     none, ascii, unicode, bold, double, block, shade or round, default none
+  --name NAME       Name of the array for rust and c output, default BITMAP
+
+  --be and --aligned also set the layout of rust and c output.
+  Numbers may be written in hex with a 0x prefix.
+
+  --help            Print this help
+";
+
+/// Parsed command-line options.
+struct Options {
+    file: Option<String>,
+    format: Option<String>,
+    bytes: Option<String>,
+    width: Option<usize>,
+    height: Option<usize>,
+    be: bool,
+    aligned: bool,
+    threshold: u8,
+    invert: bool,
+    offset: u64,
+    length: Option<usize>,
+    scan: bool,
+    step: Option<usize>,
+    count: Option<usize>,
+    output: String,
+    style: Style,
+    frame: Frame,
+    name: String
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("bitmap_writer: {}\nTry 'bitmap_writer --help' for more information.", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("bitmap_writer: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parse the command-line arguments, `None` if help was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        file: None, format: None, bytes: None, width: None, height: None, be: false, aligned: false, threshold: 128, invert: false,
+        offset: 0, length: None, scan: false, step: None, count: None,
+        output: "text".to_string(), style: Style::UnicodeBlock1x2, frame: Frame::NoFrame, name: "BITMAP".to_string()
+    };
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+            _ => (arg.clone(), None)
+        };
+        let mut value = |name: &str| -> Result<String, String> {
+            return inline.clone().or_else(|| args.next()).ok_or(format!("option {} requires a value", name));
+        };
+        let number = |name: &str, value: String| -> Result<usize, String> {
+            let parsed = match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+                Some(hex) => usize::from_str_radix(hex, 16),
+                None => value.parse::<usize>()
+            };
+            return parsed.map_err(|_| format!("invalid number for {}: {}", name, value));
+        };
+
+        match flag.as_str() {
+            "--help" | "-h" => { return Ok(None); }
+            "--format" => { options.format = Some(value(&flag)?); }
+            "--bytes" => { options.bytes = Some(value(&flag)?); }
+            "--width" => { options.width = Some(number(&flag, value(&flag)?)?); }
+            "--height" => { options.height = Some(number(&flag, value(&flag)?)?); }
+            "--be" => { options.be = true; }
+            "--aligned" => { options.aligned = true; }
+            "--threshold" => {
+                let threshold = number(&flag, value(&flag)?)?;
+                options.threshold = u8::try_from(threshold).map_err(|_| format!("threshold must be 0 to 255: {}", threshold))?;
+            }
+            "--invert" => { options.invert = true; }
+            "--offset" => { options.offset = number(&flag, value(&flag)?)? as u64; }
+            "--length" => { options.length = Some(number(&flag, value(&flag)?)?); }
+            "--scan" => { options.scan = true; }
+            "--step" => { options.step = Some(number(&flag, value(&flag)?)?.max(1)); }
+            "--count" => { options.count = Some(number(&flag, value(&flag)?)?); }
+            "--output" => { options.output = value(&flag)?; }
+            "--style" => { options.style = parse_style(&value(&flag)?)?; }
+            "--frame" => { options.frame = parse_frame(&value(&flag)?)?; }
+            "--name" => { options.name = value(&flag)?; }
+            "-" => { options.file = Some(arg); }
+            _ if arg.starts_with('-') => { return Err(format!("unknown option: {}", arg)); }
+            _ => {
+                if options.file.is_some() { return Err(format!("unexpected argument: {}", arg)); }
+                options.file = Some(arg);
+            }
+        }
+    }
+
+    if !matches!(options.output.as_str(), "text" | "svg" | "html" | "png" | "rust" | "c") {
+        return Err(format!("unknown output format: {}", options.output));
+    }
+    if options.scan && (options.width.is_none() || options.height.is_none()) {
+        return Err("--scan requires --width and --height".to_string());
+    }
+    if options.scan && options.output != "text" {
+        return Err("--scan only writes text".to_string());
+    }
+    if options.bytes.is_some() && options.file.is_some() {
+        return Err("--bytes and FILE cannot be used together".to_string());
+    }
+    return Ok(Some(options));
+}
+
+fn parse_style(name: &str) -> Result<Style, String> {
+    return match name {
+        "ascii" => Ok(Style::ASCII1x1('#')),
+        "block1x1" => Ok(Style::UnicodeBlock1x1),
+        "block1x2" => Ok(Style::UnicodeBlock1x2),
+        "block2x2" => Ok(Style::UnicodeBlock2x2),
+        "sextant1x3" => Ok(Style::UnicodeSextant1x3),
+        "sextant2x3" => Ok(Style::UnicodeSextant2x3),
+        _ => Err(format!("unknown style: {}", name))
+    };
+}
+
+fn parse_frame(name: &str) -> Result<Frame, String> {
+    return match name {
+        "none" => Ok(Frame::NoFrame),
+        "ascii" => Ok(Frame::ASCIIFrame),
+        "unicode" => Ok(Frame::UnicodeFrame),
+        "bold" => Ok(Frame::UnicodeBoldFrame),
+        "double" => Ok(Frame::UnicodeDoubleUFrame),
+        "block" => Ok(Frame::UnicodeBlockFrame),
+        "shade" => Ok(Frame::UnicodeShadeFrame),
+        "round" => Ok(Frame::UnicodeRoundFrame),
+        _ => Err(format!("unknown frame: {}", name))
+    };
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let data = match options.file.as_deref() {
+        None | Some("-") => {
+            let mut data = Vec::new();
+            match &options.bytes {
+                Some(text) => { data.extend_from_slice(text.as_bytes()); }
+                None => { std::io::stdin().lock().read_to_end(&mut data).map_err(|e| format!("cannot read stdin: {}", e))?; }
+            }
+            let start = usize::try_from(options.offset).unwrap_or(usize::MAX).min(data.len());
+            let end = options.length.map_or(data.len(), |length| data.len().min(start.saturating_add(length)));
+            data[start..end].to_vec()
+        }
+        Some(path) => {
+            let region = FileRegion::read(path, options.offset, options.length).map_err(|e| format!("cannot read {}: {}", path, e))?;
+            region.data().to_vec()
+        }
+    };
+
+    let mut format = options.format.clone().unwrap_or_else(|| detect_format(&data).to_string());
+    let data = if format == "hex" {
+        let text = std::str::from_utf8(&data).map_err(|_| "cannot decode hex: not UTF-8 text".to_string())?;
+        format = "raw".to_string();
+        parse_bytes(text).map_err(|e| format!("cannot decode hex: {}", e))?
+    } else {
+        data
+    };
+
+    let mut writer = Writer::new();
+    writer.style(options.style).frame(options.frame);
+    let mut out = std::io::stdout().lock();
+
+    if options.scan {
+        if format != "raw" { return Err(format!("--scan requires raw data, not {}", format)); }
+        writer.be(options.be).byte_aligned(options.aligned);
+        let (width, height) = (options.width.unwrap_or(0), options.height.unwrap_or(0));
+        let result = Scan::new(&writer, &data, width, height).step(options.step).count(options.count).base(options.offset).print_to(&mut out);
+        return finish(result, &mut out);
+    }
+
+    let decoded = match format.as_str() {
+        "raw" => None,
+        "pbm" => Some(BitmapBuf::from_pbm(&data)),
+        "xbm" => Some(BitmapBuf::from_xbm(&String::from_utf8_lossy(&data))),
+        "bmp" => Some(BitmapBuf::from_bmp(&data, options.threshold, options.invert)),
+        "png" => Some(BitmapBuf::from_png(&data, options.threshold, options.invert)),
+        _ => { return Err(format!("unknown input format: {}", format)); }
+    };
+
+    let decoded = decoded.transpose().map_err(|e| format!("cannot decode {}: {}", format, e))?;
+    let bitmap = match &decoded {
+        Some(decoded) => decoded.as_bitmap(),
+        None => {
+            let width = options.width.filter(|w| *w > 0).ok_or("raw data requires --width")?;
+            let bits_per_row = if options.aligned { width.div_ceil(8) * 8 } else { width };
+            let height = options.height.unwrap_or(data.len() * 8 / bits_per_row);
+            writer.be(options.be).byte_aligned(options.aligned);
+            Bitmap::new(width, height, &data)
+        }
+    };
+
+    let result = match options.output.as_str() {
+        "svg" => Svg::new(&writer, &bitmap).print_to(&mut out),
+        "html" => Html::new(&writer, &bitmap).print_to(&mut out),
+        "png" => Png::new(&writer, &bitmap).write(&mut out),
+        "rust" | "c" => {
+            Codegen::new(&writer, &bitmap, &options.name)
+                .language(if options.output == "c" { Language::C } else { Language::Rust })
+                .literal(if options.output == "c" { Literal::Hex } else { Literal::Binary })
+                .be(options.be)
+                .byte_aligned(options.aligned)
+                .print_to(&mut out)
+        }
+        _ => writer.print_to(&mut out, &bitmap)
+    };
+    return finish(result, &mut out);
+}
+
+/// Turn the result of writing the output into an error message, apart from
+/// a broken pipe.
+fn finish(result: std::io::Result<()>, out: &mut impl Write) -> Result<(), String> {
+    return match result {
+        Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => Err(format!("cannot write output: {}", e)),
+        _ => { let _ = out.flush(); Ok(()) }
+    };
+}
+
+/// Guess the format of the input from its first bytes.
+fn detect_format(data: &[u8]) -> &'static str {
+    if data.starts_with(&[0x89, b'P', b'N', b'G']) { return "png"; }
+    if data.starts_with(b"BM") { return "bmp"; }
+    if data.starts_with(b"P1") || data.starts_with(b"P4") { return "pbm"; }
+    if data.starts_with(b"#define") { return "xbm"; }
+    if std::str::from_utf8(data).is_ok_and(|text| parse_bytes(text).is_ok_and(|bytes| !bytes.is_empty())) { return "hex"; }
+    return "raw";
+}
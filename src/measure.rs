@@ -0,0 +1,105 @@
+use crate::source::Image;
+use crate::diff::Diff;
+use crate::writer::Writer;
+
+/// Size of the text written for a bitmap, to size buffers up front.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Measurement {
+    /// Number of text lines.
+    pub lines: usize,
+    /// Number of characters of the longest text line, not counting ANSI
+    /// commands and the line break.
+    pub columns: usize,
+    /// Number of bytes of the UTF-8 text, including frame characters,
+    /// line breaks and ANSI commands.
+    pub bytes: usize
+}
+
+/// ```no_std``` compatible and without allocation.
+impl Writer<'_> {
+    /// Measure the output of `write(..)` without storing it, e.g. to size a
+    /// fixed-capacity string or a DMA buffer. The bitmap is rendered once
+    /// to count the bytes.
+    ///
+    /// ```
+    /// let bitmap = bitmap_writer::Bitmap::new(8, 4, &[0x3c, 0x42, 0x42, 0x3c]);
+    /// let mut w = bitmap_writer::Writer::new();
+    /// w.frame(bitmap_writer::Frame::UnicodeFrame).ansi_position(2, 10);
+    ///
+    /// let measurement = w.measure(&bitmap);
+    /// assert_eq!((measurement.lines, measurement.columns), (4, 10));
+    /// assert_eq!(measurement.bytes, w.chars(&bitmap).map(char::len_utf8).sum());
+    /// ```
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to measure.
+    ///
+    /// # Returns
+    /// - Lines, columns and bytes of the output.
+    pub fn measure(&self, bitmap: &(impl Image + ?Sized)) -> Measurement {
+        let mut counter = Counter::new();
+        let _ = self._write_bitmap(&mut counter, bitmap);
+        return counter._measurement();
+    }
+}
+
+/// ```no_std``` compatible and without allocation.
+impl Diff<'_> {
+    /// Measure the output of `write(..)` without storing it, including the
+    /// colors and the summary line, like `Writer::measure(..)`.
+    ///
+    /// # Returns
+    /// - Lines, columns and bytes of the output.
+    pub fn measure(&self) -> Measurement {
+        let mut counter = Counter::new();
+        let _ = self._write_diff(&mut counter);
+        return counter._measurement();
+    }
+}
+
+/// Sink counting the bytes, lines and characters per line written.
+struct Counter {
+    _bytes: usize,
+    _lines: usize,
+    _columns: usize,
+    _column: usize,
+    _state: u8
+}
+
+impl Counter {
+    fn new() -> Counter {
+        return Counter { _bytes: 0, _lines: 0, _columns: 0, _column: 0, _state: 0 };
+    }
+
+    /// Count text, which may end or start in the middle of a UTF-8
+    /// character or an ANSI command.
+    fn _feed(&mut self, bytes: &[u8]) {
+        self._bytes += bytes.len();
+        for byte in bytes {
+            match (self._state, byte) {
+                (0, 0x1b) => { self._state = 1; }
+                (0, b'\n') => {
+                    self._lines += 1;
+                    self._columns = self._columns.max(self._column);
+                    self._column = 0;
+                }
+                (0, _) if byte & 0xc0 != 0x80 => { self._column += 1; }
+                (1, b'[') => { self._state = 2; }
+                (1, _) | (_, 0x40..=0x7e) => { self._state = 0; }
+                _ => { }
+            }
+        }
+    }
+
+    fn _measurement(&self) -> Measurement {
+        let open = if self._column > 0 { 1 } else { 0 };
+        return Measurement { lines: self._lines + open, columns: self._columns.max(self._column), bytes: self._bytes };
+    }
+}
+
+impl core::fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self._feed(s.as_bytes());
+        return Ok(());
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{Bitmap, Style, Writer};
+use crate::source::Image;
+
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+
+/// A key press understood by the `Pager`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Key {
+    Left,
+    Right,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    ZoomIn,
+    ZoomOut,
+    Quit
+}
+
+impl Key {
+    /// Read the keys pressed from a terminal in raw mode, which arrive
+    /// together when a key is held down. Escape sequences arrive in one
+    /// read, so an Esc at the end of a read was pressed on its own.
+    ///
+    /// ```
+    /// use bitmap_writer::Key;
+    ///
+    /// let keys = Key::read(&mut &b"jk\x1b[A\x1bOC\x1b[6~+"[..]).unwrap();
+    /// assert_eq!(keys, [Key::Down, Key::Up, Key::Up, Key::Right, Key::PageDown, Key::ZoomIn]);
+    ///
+    /// // Unknown keys and escape sequences are skipped.
+    /// assert_eq!(Key::read(&mut &b"x\x1b[1;5A-"[..]).unwrap(), [Key::ZoomOut]);
+    ///
+    /// // `q`, a lone Esc, Ctrl-C and the end of the input quit.
+    /// for input in [&b"q"[..], b"\x1b", b"\x03", b""] {
+    ///     assert_eq!(Key::read(&mut &input[..]).unwrap(), [Key::Quit]);
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    /// - `r`: The stream to read from, e.g. stdin.
+    ///
+    /// # Returns
+    /// - The keys read, empty if none of them is understood by the pager.
+    pub fn read(r: &mut impl Read) -> std::io::Result<Vec<Key>> {
+        let mut buffer = [0u8; 64];
+        let length = r.read(&mut buffer)?;
+        if length == 0 { return Ok(vec![Key::Quit]); }
+        let bytes = &buffer[..length];
+
+        let mut keys = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let key = match bytes[i] {
+                b'h' => Some(Key::Left),
+                b'j' => Some(Key::Down),
+                b'k' => Some(Key::Up),
+                b'l' => Some(Key::Right),
+                b'+' | b'=' => Some(Key::ZoomIn),
+                b'-' => Some(Key::ZoomOut),
+                b' ' => Some(Key::PageDown),
+                b'b' => Some(Key::PageUp),
+                b'q' | b'Q' | 0x03 => Some(Key::Quit),
+                0x1b if bytes.get(i + 1) == Some(&b'[') => {
+                    let start = i + 2;
+                    let end = bytes[start..].iter().position(|b| (0x40..=0x7e).contains(b)).map_or(bytes.len(), |n| start + n + 1);
+                    i = end - 1;
+                    match &bytes[start..end] {
+                        b"A" => Some(Key::Up),
+                        b"B" => Some(Key::Down),
+                        b"C" => Some(Key::Right),
+                        b"D" => Some(Key::Left),
+                        b"5~" => Some(Key::PageUp),
+                        b"6~" => Some(Key::PageDown),
+                        _ => None
+                    }
+                }
+                // Arrow keys in the application cursor mode of the terminal.
+                0x1b if bytes.get(i + 1) == Some(&b'O') && i + 2 < bytes.len() => {
+                    i += 2;
+                    match bytes[i] {
+                        b'A' => Some(Key::Up),
+                        b'B' => Some(Key::Down),
+                        b'C' => Some(Key::Right),
+                        b'D' => Some(Key::Left),
+                        _ => None
+                    }
+                }
+                0x1b => Some(Key::Quit),
+                _ => None
+            };
+            keys.extend(key);
+            i += 1;
+        }
+        return Ok(keys);
+    }
+}
+
+/// Interactive terminal viewer that pans across a bitmap larger than the
+/// screen.
+///
+/// The bitmap is written with the `Writer` given to the pager, so frame,
+/// endianess and byte-alignment settings are honored. Zooming switches
+/// between the styles from one pixel per character up to six pixels per
+/// character.
+///
+/// Requires `features=["std"]` in ```cargo.toml```.
+///
+/// Keys:
+/// - Arrow keys or `h`, `j`, `k`, `l`: Move by one character.
+/// - Page up and page down: Move by one screen.
+/// - `+` and `-`: Zoom in and out.
+/// - `q`, Esc or Ctrl-C: Quit.
+pub struct Pager<'a, I: Image + ?Sized = Bitmap<'a>> {
+    _writer: Writer<'a>,
+    _bitmap: &'a I,
+    _styles: [Style; 5],
+    _zoom: usize,
+    _x: usize,
+    _y: usize
+}
+
+impl<'a, I: Image + ?Sized> Pager<'a, I> {
+    /// Construct a pager for a bitmap.
+    ///
+    /// The style of the writer determines the initial zoom level.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to write the visible part of the bitmap.
+    /// - `bitmap`: The bitmap to view, or a `PixelSource`.
+    ///
+    /// # Returns
+    /// - Pager instance.
+    pub fn new(writer: Writer<'a>, bitmap: &'a I) -> Pager<'a, I> {
+        let mut styles = [
+            Style::UnicodeBlock1x1,
+            Style::UnicodeBlock1x2,
+            Style::UnicodeSextant1x3,
+            Style::UnicodeBlock2x2,
+            Style::UnicodeSextant2x3
+        ];
+        if let Style::ASCII1x1(_) = writer._style {
+            styles[0] = writer._style;
+        }
+        let zoom = styles.iter().position(|style| *style == writer._style).unwrap_or(1);
+        return Pager { _writer: writer, _bitmap: bitmap, _styles: styles, _zoom: zoom, _x: 0, _y: 0 };
+    }
+
+    /// Move the visible area or change the zoom level.
+    ///
+    /// The visible area stays within the bitmap and starts on a character
+    /// cell, so it moves back when zooming out near the edges.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Key, Pager, Style, Writer};
+    ///
+    /// /// The position shown in the status line.
+    /// fn position(pager: &mut Pager) -> String {
+    ///     let mut out = Vec::new();
+    ///     pager.render(&mut out, 6, 24).unwrap();
+    ///     let status = String::from_utf8(out).unwrap();
+    ///     return status.split("\x1b[7m ").nth(1).unwrap().split(" of").next().unwrap().to_string();
+    /// }
+    ///
+    /// let bitmap = Bitmap::new(40, 20, &[0x55; 100]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#'));
+    /// let mut pager = Pager::new(w, &bitmap);
+    ///
+    /// // A terminal of 6 lines and 24 columns shows 24x5 pixels above the status line.
+    /// assert!(pager.handle(Key::Right, 6, 24) && pager.handle(Key::Down, 6, 24));
+    /// assert_eq!(position(&mut pager), "1,1");
+    /// pager.handle(Key::PageDown, 6, 24);
+    /// assert_eq!(position(&mut pager), "1,6");
+    ///
+    /// for _i in 0..30 {
+    ///     pager.handle(Key::Right, 6, 24);
+    ///     pager.handle(Key::PageDown, 6, 24);
+    /// }
+    /// assert_eq!(position(&mut pager), "16,15");
+    ///
+    /// // Two pixels per character show 24x10 pixels.
+    /// pager.handle(Key::ZoomOut, 6, 24);
+    /// assert_eq!(position(&mut pager), "16,10");
+    ///
+    /// assert!(!pager.handle(Key::Quit, 6, 24));
+    /// ```
+    ///
+    /// # Arguments
+    /// - `key`: The key that was pressed.
+    /// - `lines`: Height of the terminal in characters.
+    /// - `columns`: Width of the terminal in characters.
+    ///
+    /// # Returns
+    /// - `false` if the pager should quit.
+    pub fn handle(&mut self, key: Key, lines: usize, columns: usize) -> bool {
+        let (cell_w, cell_h) = self._styles[self._zoom]._cell_size();
+        let (_view_w, view_h) = self._view_size(lines, columns);
+        match key {
+            Key::Left => { self._x = self._x.saturating_sub(cell_w); }
+            Key::Right => { self._x += cell_w; }
+            Key::Up => { self._y = self._y.saturating_sub(cell_h); }
+            Key::Down => { self._y += cell_h; }
+            Key::PageUp => { self._y = self._y.saturating_sub(view_h); }
+            Key::PageDown => { self._y += view_h; }
+            Key::ZoomIn => { self._zoom = self._zoom.saturating_sub(1); }
+            Key::ZoomOut => { self._zoom = (self._zoom + 1).min(self._styles.len() - 1); }
+            Key::Quit => { return false; }
+        }
+        self._clamp(lines, columns);
+        return true;
+    }
+
+    /// Write the visible area of the bitmap and a status line, filling the
+    /// whole terminal.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Frame, Pager, Style, Writer};
+    ///
+    /// let bitmap = Bitmap::new(4, 2, &[0b10010110]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame);
+    ///
+    /// let mut out = Vec::new();
+    /// Pager::new(w, &bitmap).render(&mut out, 5, 14).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "\x1b[2J\
+    ///     \x1b[1;1H.----.\n\
+    ///     \x1b[2;1H|#  #|\n\
+    ///     \x1b[3;1H| ## |\n\
+    ///     \x1b[4;1H'----'\n\
+    ///     \x1b[5;1H\x1b[7m 0,0 of 4x2 | \x1b[0m");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `w`: The stream to write to.
+    /// - `lines`: Height of the terminal in characters.
+    /// - `columns`: Width of the terminal in characters.
+    pub fn render(&mut self, w: &mut impl Write, lines: usize, columns: usize) -> std::io::Result<()> {
+        self._clamp(lines, columns);
+        let (view_w, view_h) = self._view_size(lines, columns);
+
+        write!(w, "\x1b[2J")?;
+        self._writer
+            .style(self._styles[self._zoom])
+            .ansi_position_restore(false)
+            .ansi_position(1, 1)
+            .viewport(self._x, self._y, view_w, view_h)
+            .write(w, self._bitmap);
+
+        write!(w, "\x1b[{};1H\x1b[7m", lines)?;
+        let status = format!(
+            " {},{} of {}x{} | {}x{} pixels per character | arrows/hjkl: move, PgUp/PgDn: page, +/-: zoom, q: quit ",
+            self._x, self._y, self._writer._size(self._bitmap).0, self._writer._size(self._bitmap).1, self._styles[self._zoom]._cell_size().0, self._styles[self._zoom]._cell_size().1
+        );
+        write!(w, "{}\x1b[0m", status.chars().take(columns).collect::<String>())?;
+        return w.flush();
+    }
+
+    /// Take over the terminal and view the bitmap until `q` is pressed.
+    ///
+    /// The terminal is switched to the alternate screen with echo, line
+    /// buffering and signal keys disabled through `stty`, and restored
+    /// afterwards, also when writing fails or the pager panics. Ctrl-C is
+    /// read as a key and quits like `q`.
+    pub fn run(&mut self) -> std::io::Result<()> {
+        let _terminal = Terminal::enter();
+        let mut out = std::io::stdout().lock();
+        let mut stdin = std::io::stdin().lock();
+        loop {
+            let (lines, columns) = _terminal_size();
+            self.render(&mut out, lines, columns)?;
+            for key in Key::read(&mut stdin)? {
+                if !self.handle(key, lines, columns) { return Ok(()); }
+            }
+        }
+    }
+
+    /// Pixel size of the area that fits onto the terminal, leaving room for
+    /// the frame, rulers and the status line.
+    fn _view_size(&mut self, lines: usize, columns: usize) -> (usize, usize) {
+        let (cell_w, cell_h) = self._styles[self._zoom]._cell_size();
+        let (decoration_w, decoration_h) = self._writer.style(self._styles[self._zoom])._decoration_size(self._bitmap);
+        let cells_w = columns.saturating_sub(decoration_w).max(1);
+        let cells_h = lines.saturating_sub(decoration_h + 1).max(1);
+        return (cells_w * cell_w, cells_h * cell_h);
+    }
+
+    fn _clamp(&mut self, lines: usize, columns: usize) {
+        let (cell_w, cell_h) = self._styles[self._zoom]._cell_size();
+        let (view_w, view_h) = self._view_size(lines, columns);
+        let (width, height) = self._writer._size(self._bitmap);
+        self._x = self._x.min(width.saturating_sub(view_w)) / cell_w * cell_w;
+        self._y = self._y.min(height.saturating_sub(view_h)) / cell_h * cell_h;
+    }
+}
+
+fn _stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty").args(args).stdin(Stdio::inherit()).stderr(Stdio::null()).output().ok()?;
+    if !output.status.success() { return None; }
+    return String::from_utf8(output.stdout).ok();
+}
+
+fn _terminal_size() -> (usize, usize) {
+    if let Some(size) = _stty(&["size"]) {
+        let mut parts = size.split_whitespace().map(|v| v.parse::<usize>());
+        if let (Some(Ok(lines)), Some(Ok(columns))) = (parts.next(), parts.next()) && lines > 0 && columns > 0 {
+            return (lines, columns);
+        }
+    }
+    return (24, 80);
+}
+
+/// Terminal settings of the pager, restored when dropped.
+struct Terminal {
+    _saved: Option<String>
+}
+
+impl Terminal {
+    fn enter() -> Terminal {
+        let saved = _stty(&["-g"]).map(|s| s.trim().to_string());
+        // Wait for the first byte, then up to a tenth of a second for the
+        // rest of an escape sequence, so a lone Esc is read on its own.
+        _stty(&["-icanon", "-echo", "-isig", "min", "1", "time", "1"]);
+        let mut out = std::io::stdout();
+        let _ = write!(out, "\x1b[?1049h\x1b[?25l").and_then(|_| out.flush());
+        return Terminal { _saved: saved };
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let mut out = std::io::stdout();
+        let _ = write!(out, "\x1b[?25h\x1b[?1049l").and_then(|_| out.flush());
+        if let Some(saved) = &self._saved {
+            _stty(&[saved.as_str()]);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::Frame;
+use crate::bitmap::BitmapBuf;
+use crate::writer::Writer;
+
+use alloc::vec::Vec;
+
+/// Error when converting text back into a bitmap.
+///
+/// Lines and columns count from 1 and refer to characters of the text,
+/// ignoring ANSI commands.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// A character that is not part of the writer's style.
+    UnknownGlyph { line: usize, column: usize, glyph: char },
+    /// A character where the writer's frame was expected.
+    MissingFrame { line: usize, column: usize },
+    /// A value that is not a byte, see `parse_bytes(..)`.
+    InvalidByte { line: usize, column: usize }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return match self {
+            ParseError::UnknownGlyph { line, column, glyph } => write!(f, "unknown glyph {:?} at line {}, column {}", glyph, line, column),
+            ParseError::MissingFrame { line, column } => write!(f, "missing frame at line {}, column {}", line, column),
+            ParseError::InvalidByte { line, column } => write!(f, "invalid byte at line {}, column {}", line, column)
+        };
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+impl Writer<'_> {
+    /// Convert text written with this writer's style and frame back into a
+    /// bitmap, the inverse of `write(..)`.
+    ///
+    /// The margin, frame and padding are skipped and ANSI commands are
+    /// ignored. Lines that are shorter than expected, for example because an
+    /// editor removed trailing spaces, are treated as if filled with blank
+    /// spaces. Without a frame, the width is taken from the longest line.
+    ///
+    /// The resulting bitmap is as wide and high as the characters of the text
+    /// cover, so a bitmap that is not a multiple of the style's pixels per
+    /// character grows accordingly, see `BitmapBuf::crop(..)`. Scaling,
+    /// rotation, viewport and rulers are not reversed.
+    ///
+    /// ```
+    /// let text = "\
+    /// ┌────┐
+    /// │▄▀▀▄│
+    /// │ ▀▀ │
+    /// └────┘
+    /// ";
+    /// let bitmap = bitmap_writer::Writer::new()
+    ///     .frame(bitmap_writer::Frame::UnicodeFrame)
+    ///     .parse(text)
+    ///     .unwrap();
+    /// assert_eq!((bitmap.width(), bitmap.height()), (4, 4));
+    /// assert!(bitmap.pixel(1, 0) && !bitmap.pixel(0, 0));
+    ///
+    /// // ANSI commands are ignored, also those storing and restoring the cursor position.
+    /// let icon = bitmap_writer::Bitmap::new(4, 4, &[0b01101001, 0b00000110]);
+    /// let mut w = bitmap_writer::Writer::new();
+    /// w.frame(bitmap_writer::Frame::UnicodeFrame).ansi_position(2, 10).ansi_position_restore(true);
+    /// let text = w.to_string(&icon);
+    /// assert!(text.ends_with("\n\x1b[u"));
+    /// assert_eq!(w.parse(&text).unwrap(), w.to_bitmap(&icon));
+    /// ```
+    ///
+    /// # Arguments
+    /// - `text`: The text to convert.
+    ///
+    /// # Returns
+    /// - The bitmap, or the position of the first character that could not be converted.
+    pub fn parse(&self, text: &str) -> Result<BitmapBuf, ParseError> {
+        let mut lines: Vec<&str> = text.lines().collect();
+        // The ANSI command restoring the cursor position follows the last line break.
+        while lines.last().is_some_and(|line| !line.is_empty() && _strip_ansi(line).is_empty()) { lines.pop(); }
+        let lines: Vec<Vec<char>> = lines.into_iter().map(_strip_ansi).collect();
+        let framed = self._frame != Frame::NoFrame;
+        let (cell_w, cell_h) = self._style._cell_size();
+
+        let first = self._margin.top.min(lines.len());
+        let last = lines.len().saturating_sub(self._margin.bottom).max(first);
+        let content = &lines[first..last];
+        let left = self._margin.left;
+
+        let (rows, inner_w) = if framed {
+            if content.len() < 2 {
+                return Err(ParseError::MissingFrame { line: first + content.len() + 1, column: left + 1 });
+            }
+            let corner = self._frame._nine_patch(2, 0);
+            let Some(inner_w) = content[0].iter().rposition(|c| *c == corner).filter(|end| *end > left).map(|end| end - left - 1) else {
+                return Err(ParseError::MissingFrame { line: first + 1, column: content[0].len() + 1 });
+            };
+            for (i, line) in content.iter().enumerate() {
+                let (start, end) = if i == 0 { (0, 2) } else if i == content.len() - 1 { (6, 8) } else { (3, 5) };
+                let index = i.saturating_sub(1);
+                if line.get(left) != Some(&self._frame._nine_patch(start, index)) {
+                    return Err(ParseError::MissingFrame { line: first + i + 1, column: left + 1 });
+                }
+                if line.get(left + inner_w + 1) != Some(&self._frame._nine_patch(end, index)) {
+                    return Err(ParseError::MissingFrame { line: first + i + 1, column: left + inner_w + 2 });
+                }
+            }
+            (&content[1..content.len() - 1], inner_w)
+        } else {
+            (content, content.iter().map(|line| line.len().saturating_sub(left + self._margin.right)).max().unwrap_or(0))
+        };
+
+        let offset_line = first + if framed { 1 } else { 0 } + self._padding.top;
+        let offset_column = left + if framed { 1 } else { 0 } + self._padding.left;
+        let rows = &rows[self._padding.top.min(rows.len())..rows.len().saturating_sub(self._padding.bottom)];
+        let cells = inner_w.saturating_sub(self._padding.left + self._padding.right);
+
+        let mut bitmap = BitmapBuf::new(cells * cell_w, rows.len() * cell_h);
+        for (row, line) in rows.iter().enumerate() {
+            for cell in 0..cells {
+                let glyph = line.get(offset_column + cell).copied().unwrap_or(' ');
+                let Some(block) = self._style._block(glyph) else {
+                    return Err(ParseError::UnknownGlyph { line: offset_line + row + 1, column: offset_column + cell + 1, glyph });
+                };
+                for yy in 0..cell_h {
+                    for xx in 0..cell_w {
+                        if block & (1 << (yy * cell_w + xx)) != 0 {
+                            bitmap.set_pixel(cell * cell_w + xx, row * cell_h + yy, true);
+                        }
+                    }
+                }
+            }
+        }
+        return Ok(bitmap);
+    }
+}
+
+/// Characters of a line without ANSI commands.
+fn _strip_ansi(line: &str) -> Vec<char> {
+    let mut chars = Vec::new();
+    let mut escape = false;
+    let mut iter = line.chars().peekable();
+    while let Some(c) = iter.next() {
+        if escape {
+            if c.is_ascii_alphabetic() || c == '~' { escape = false; }
+        } else if c == '\x1b' && iter.peek() == Some(&'[') {
+            iter.next();
+            escape = true;
+        } else {
+            chars.push(c);
+        }
+    }
+    return chars;
+}
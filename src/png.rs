@@ -0,0 +1,457 @@
+use crate::bitmap::{Bitmap, BitmapBuf};
+use crate::decode::{DecodeError, _luminance};
+use crate::inflate::_zlib_decompress;
+use crate::writer::Writer;
+
+use alloc::vec::Vec;
+
+/// Signature at the start of every PNG file.
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Largest amount of data in a stored deflate block.
+const STORED_BLOCK: usize = 65535;
+
+/// Lookup table for the CRC32 of PNG chunks.
+const CRC_TABLE: [u32; 256] = _crc_table();
+
+/// Offset and spacing `(x, y, dx, dy)` of the seven Adam7 interlacing passes.
+const ADAM7: [(usize, usize, usize, usize); 7] = [(0, 0, 8, 8), (4, 0, 8, 8), (0, 4, 4, 8), (2, 0, 4, 4), (0, 2, 2, 4), (1, 0, 2, 2), (0, 1, 1, 2)];
+
+/// Write a bitmap as 1-bit grayscale PNG image.
+///
+/// Pixels are read as the given writer sees them, so endianess,
+/// byte-alignment, flipping, rotation, scaling and the viewport apply, while
+/// style, frame and the other text settings are ignored. By default set
+/// pixels are black and unset pixels white.
+///
+/// The image data is stored uncompressed, which is valid PNG and small
+/// enough for 1-bit images.
+///
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, Png, Writer};
+///
+/// let bitmap = Bitmap::new(8, 2, &[0b11110000, 0b00001111]);
+/// let w = Writer::new();
+/// let png = Png::new(&w, &bitmap).scale(4).to_vec();
+/// assert_eq!(&png[1..4], b"PNG");
+/// assert_eq!(&png[16..24], &[0, 0, 0, 32, 0, 0, 0, 8]);
+/// ```
+pub struct Png<'a> {
+    _writer: &'a Writer<'a>,
+    _bitmap: &'a Bitmap<'a>,
+    _scale: usize,
+    _invert: bool
+}
+
+impl<'a> Png<'a> {
+    /// Construct a PNG writer for a bitmap.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to read the bitmap.
+    /// - `bitmap`: The bitmap to write.
+    ///
+    /// # Returns
+    /// - Png instance.
+    pub fn new(writer: &'a Writer<'a>, bitmap: &'a Bitmap<'a>) -> Png<'a> {
+        return Png { _writer: writer, _bitmap: bitmap, _scale: 1, _invert: false };
+    }
+
+    /// Write every pixel as a square of pixels, on top of the writer's
+    /// scaling.
+    ///
+    /// # Arguments
+    /// - `factor`: Width and height of the square, at least 1.
+    ///
+    /// # Returns
+    /// - Reference to png.
+    pub fn scale(&mut self, factor: usize) -> &mut Self {
+        self._scale = factor.max(1);
+        return self;
+    }
+
+    /// Write set pixels white and unset pixels black, like a lit display.
+    ///
+    /// # Arguments
+    /// - `state`: `true` to invert.
+    ///
+    /// # Returns
+    /// - Reference to png.
+    pub fn invert(&mut self, state: bool) -> &mut Self {
+        self._invert = state;
+        return self;
+    }
+
+    /// Encode the PNG file.
+    ///
+    /// Bitmaps without pixels are written as a single unset pixel, as PNG
+    /// does not allow empty images.
+    ///
+    /// # Returns
+    /// - The content of the PNG file.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let (left, top, width, height) = self._writer._region(self._bitmap);
+        let (png_w, png_h) = ((width * self._scale).max(1), (height * self._scale).max(1));
+        let stride = png_w.div_ceil(8);
+
+        let mut raw = Vec::with_capacity((stride + 1) * png_h);
+        for y in 0..png_h {
+            raw.push(0);
+            let start = raw.len();
+            raw.resize(start + stride, 0);
+            for x in 0..png_w {
+                let set = x < width * self._scale && y < height * self._scale
+                    && self._writer._sample(self._bitmap, left + x / self._scale, top + y / self._scale);
+                if set == self._invert { raw[start + x / 8] |= 0x80 >> (x % 8); }
+            }
+        }
+
+        let mut png = Vec::new();
+        png.extend_from_slice(&SIGNATURE);
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(png_w as u32).to_be_bytes());
+        header.extend_from_slice(&(png_h as u32).to_be_bytes());
+        header.extend_from_slice(&[1, 0, 0, 0, 0]);
+        _chunk(&mut png, b"IHDR", &header);
+        _chunk(&mut png, b"IDAT", &_zlib_stored(&raw));
+        _chunk(&mut png, b"IEND", &[]);
+        return png;
+    }
+}
+
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+impl Png<'_> {
+    /// Write the PNG file to a stream, like a `std::fs::File`.
+    ///
+    /// # Arguments
+    /// - `w`: The stream to write to.
+    pub fn write(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        w.write_all(&self.to_vec())?;
+        return w.flush();
+    }
+}
+
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+impl BitmapBuf {
+    /// Decode a PNG file into a bitmap, setting the pixels darker than a
+    /// threshold.
+    ///
+    /// All color types, bit depths and interlacing are supported. The
+    /// brightness of a pixel is its gray value, or the luminance of its
+    /// color, blended over the color of unset pixels by its transparency.
+    /// Bit depths below 8 are scaled up and 16-bit samples rounded down to 8
+    /// bits first, so a 1-bit image works with any threshold from 1 to 255.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, BitmapBuf, Png, Writer};
+    ///
+    /// let bitmap = Bitmap::new(8, 2, &[0b11110000, 0b00001111]);
+    /// let png = Png::new(&Writer::new(), &bitmap).scale(2).to_vec();
+    ///
+    /// let decoded = BitmapBuf::from_png(&png, 128, false).unwrap();
+    /// assert_eq!((decoded.width(), decoded.height()), (16, 4));
+    /// assert!(decoded.pixel(0, 0) && !decoded.pixel(15, 0));
+    /// ```
+    ///
+    /// Other color types, bit depths, filters, interlacing and compression:
+    ///
+    /// ```
+    /// use bitmap_writer::{BitmapBuf, Style, Writer};
+    /// # const FIXED: &[u8] = &[
+    /// #     0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    /// #     0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x05, 0x08, 0x00, 0x00, 0x00, 0x00, 0x5d, 0xfa, 0xf2,
+    /// #     0x89, 0x00, 0x00, 0x00, 0x35, 0x49, 0x44, 0x41, 0x54, 0x78, 0x01, 0x63, 0x68, 0xa8, 0xa9, 0xac,
+    /// #     0xab, 0xae, 0xa8, 0x6d, 0x66, 0xac, 0x62, 0x63, 0xfd, 0xcb, 0xfa, 0xf7, 0xef, 0x1f, 0x26, 0x66,
+    /// #     0xe6, 0x5f, 0xcc, 0xbf, 0x81, 0x88, 0xd9, 0x8a, 0x85, 0xf5, 0x37, 0x0b, 0x23, 0xcb, 0x1f, 0x16,
+    /// #     0x9e, 0xdf, 0x3f, 0xfe, 0xfe, 0xff, 0xfb, 0x9d, 0x0f, 0x00, 0xb3, 0x91, 0x14, 0xa4, 0x32, 0x31,
+    /// #     0xc8, 0x8c, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82
+    /// # ];
+    /// # const DYNAMIC: &[u8] = &[
+    /// #     0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    /// #     0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0xc6, 0xca, 0x60,
+    /// #     0xbf, 0x00, 0x00, 0x00, 0x47, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x75, 0x8d, 0x01, 0x0a, 0x00,
+    /// #     0x21, 0x08, 0x04, 0xc7, 0xea, 0xe1, 0xf6, 0x72, 0xcf, 0xd5, 0x20, 0x38, 0x68, 0xb0, 0xdd, 0x54,
+    /// #     0x54, 0xe2, 0x81, 0x05, 0x06, 0x04, 0x58, 0x3f, 0x65, 0x4a, 0x87, 0x2c, 0xfb, 0xa9, 0x56, 0xbd,
+    /// #     0x08, 0x05, 0x4c, 0x67, 0x27, 0xfa, 0xba, 0xea, 0xc9, 0x76, 0xe9, 0xba, 0x7b, 0x7e, 0xd4, 0x1c,
+    /// #     0x75, 0xaf, 0xf5, 0xd8, 0xe8, 0x22, 0xe7, 0xe4, 0x35, 0xe3, 0xc1, 0x07, 0x42, 0x70, 0x45, 0xda,
+    /// #     0x87, 0xfb, 0x6a, 0xf4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82
+    /// # ];
+    /// # const ADAM7: &[u8] = &[
+    /// #     0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    /// #     0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x07, 0x01, 0x00, 0x00, 0x00, 0x01, 0x85, 0xe7, 0x6a,
+    /// #     0x5b, 0x00, 0x00, 0x00, 0x15, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0x60, 0x80, 0x03, 0x05,
+    /// #     0x30, 0x39, 0x01, 0x08, 0x19, 0x18, 0xea, 0xa1, 0x08, 0x00, 0x18, 0xb0, 0x02, 0xbe, 0xc9, 0x52,
+    /// #     0x2c, 0xe4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82
+    /// # ];
+    /// # const PALETTE: &[u8] = &[
+    /// #     0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    /// #     0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x04, 0x02, 0x03, 0x00, 0x00, 0x00, 0xce, 0xa3, 0x96,
+    /// #     0x63, 0x00, 0x00, 0x00, 0x0c, 0x50, 0x4c, 0x54, 0x45, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0xa0,
+    /// #     0x00, 0x00, 0x00, 0x00, 0x00, 0xe1, 0x90, 0x13, 0xa3, 0x00, 0x00, 0x00, 0x04, 0x74, 0x52, 0x4e,
+    /// #     0x53, 0xff, 0xff, 0xff, 0x00, 0x40, 0x2a, 0xa9, 0xf4, 0x00, 0x00, 0x00, 0x14, 0x49, 0x44, 0x41,
+    /// #     0x54, 0x78, 0xda, 0x63, 0x60, 0xe5, 0x67, 0x10, 0xf0, 0x67, 0x10, 0xf5, 0x67, 0x98, 0x90, 0x0f,
+    /// #     0x00, 0x06, 0xb6, 0x01, 0xd7, 0xb7, 0x84, 0xae, 0xc4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e,
+    /// #     0x44, 0xae, 0x42, 0x60, 0x82
+    /// # ];
+    /// # const GRAY16: &[u8] = &[
+    /// #     0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    /// #     0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x53, 0xfe,
+    /// #     0xfc, 0x00, 0x00, 0x00, 0x02, 0x74, 0x52, 0x4e, 0x53, 0x00, 0x00, 0x76, 0x93, 0xcd, 0x38, 0x00,
+    /// #     0x00, 0x00, 0x19, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x05, 0xc1, 0x01, 0x01, 0x00, 0x00, 0x08,
+    /// #     0x83, 0x30, 0x4c, 0xce, 0x9b, 0xe3, 0x86, 0x0d, 0x10, 0x16, 0x57, 0xf6, 0x39, 0x5a, 0x07, 0x7a,
+    /// #     0x17, 0x49, 0x59, 0x93, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82
+    /// # ];
+    ///
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#'));
+    /// let decode = |png: &[u8]| format!("{}", w.display(&BitmapBuf::from_png(png, 128, false).unwrap().as_bitmap()));
+    ///
+    /// // 8-bit gray, a row for each filter, fixed Huffman codes.
+    /// assert_eq!(decode(FIXED), " ###### \n#      #\n# #  # #\n#      #\n ###### \n");
+    ///
+    /// // Mixed filters, dynamic Huffman codes and repeated strings.
+    /// assert_eq!(decode(DYNAMIC).lines().collect::<Vec<_>>(), [
+    ///     "                        ",
+    ///     "  ####   #   #  #####   ",
+    ///     "  #   #   # #     #     ",
+    ///     "  ####     #      #     ",
+    ///     "  #   #    #      #     ",
+    ///     "  ####     #      #     ",
+    ///     "                        ",
+    ///     "########################"
+    /// ]);
+    ///
+    /// // 1-bit gray, Adam7 interlacing of a width and height not divisible by 8.
+    /// assert_eq!(decode(ADAM7).lines().collect::<Vec<_>>(), [
+    ///     "#########",
+    ///     "#       #",
+    ///     "# ## ## #",
+    ///     "#       #",
+    ///     "# ##### #",
+    ///     "#       #",
+    ///     "#########"
+    /// ]);
+    ///
+    /// // 2-bit palette of white, black, dark red and transparent black.
+    /// assert_eq!(decode(PALETTE), "  ##    \n #  #   \n ####   \n##  ##  \n");
+    ///
+    /// // 16-bit gray rounded down to 8 bits, with 0 marked transparent.
+    /// assert_eq!(decode(GRAY16), "#  #\n # #\n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `png`: The content of the PNG file.
+    /// - `threshold`: Pixels with a brightness below are set, 0 to 255.
+    /// - `invert`: Set the pixels at or above the threshold instead, for images of lit pixels on a dark background.
+    ///
+    /// # Returns
+    /// - The bitmap, or why the file could not be decoded.
+    pub fn from_png(png: &[u8], threshold: u8, invert: bool) -> Result<BitmapBuf, DecodeError> {
+        if png.get(..8) != Some(&SIGNATURE[..]) { return Err(DecodeError::Signature); }
+
+        let mut header: Option<&[u8]> = None;
+        let mut palette: &[u8] = &[];
+        let mut transparency: &[u8] = &[];
+        let mut data = Vec::new();
+        let mut position = 8;
+        loop {
+            let length = png.get(position..position + 4).ok_or(DecodeError::Data)?;
+            let length = u32::from_be_bytes([length[0], length[1], length[2], length[3]]) as usize;
+            let chunk = png.get(position + 4..position + 8 + length).ok_or(DecodeError::Data)?;
+            let crc = png.get(position + 8 + length..position + 12 + length).ok_or(DecodeError::Data)?;
+            if _crc32(chunk) != u32::from_be_bytes([crc[0], crc[1], crc[2], crc[3]]) { return Err(DecodeError::Data); }
+            position += 12 + length;
+
+            let (kind, content) = chunk.split_at(4);
+            match kind {
+                b"IHDR" => { header = Some(content); }
+                b"PLTE" => { palette = content; }
+                b"tRNS" => { transparency = content; }
+                b"IDAT" => { data.extend_from_slice(content); }
+                b"IEND" => { break; }
+                _ => { }
+            }
+        }
+
+        let header = header.filter(|h| h.len() == 13).ok_or(DecodeError::Header)?;
+        let width = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let height = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let (depth, color, interlace) = (header[8] as usize, header[9], header[12]);
+        let channels = match (color, depth) {
+            (0, 1 | 2 | 4 | 8 | 16) => 1,
+            (2, 8 | 16) => 3,
+            (3, 1 | 2 | 4 | 8) => 1,
+            (4, 8 | 16) => 2,
+            (6, 8 | 16) => 4,
+            _ => { return Err(DecodeError::Header); }
+        };
+        if header[10] != 0 || header[11] != 0 || interlace > 1 || (color == 3 && palette.is_empty()) {
+            return Err(DecodeError::Header);
+        }
+        width.checked_mul(height).and_then(|pixels| pixels.checked_mul(channels * depth)).ok_or(DecodeError::Header)?;
+
+        let raw = _zlib_decompress(&data).ok_or(DecodeError::Data)?;
+        let bits = channels * depth;
+        let step = bits.div_ceil(8);
+        let passes: &[(usize, usize, usize, usize)] = if interlace == 1 { &ADAM7 } else { &[(0, 0, 1, 1)] };
+        let expected: usize = passes.iter().map(|(x0, y0, dx, dy)| {
+            let (pass_w, pass_h) = (width.saturating_sub(*x0).div_ceil(*dx), height.saturating_sub(*y0).div_ceil(*dy));
+            if pass_w == 0 { 0 } else { pass_h * (1 + (pass_w * bits).div_ceil(8)) }
+        }).sum();
+        if raw.len() < expected { return Err(DecodeError::Data); }
+
+        let mut bitmap = BitmapBuf::new(width, height);
+        let mut offset = 0;
+        for (x0, y0, dx, dy) in passes {
+            let pass_w = width.saturating_sub(*x0).div_ceil(*dx);
+            let pass_h = height.saturating_sub(*y0).div_ceil(*dy);
+            if pass_w == 0 || pass_h == 0 { continue; }
+            let stride = (pass_w * bits).div_ceil(8);
+
+            let mut previous = alloc::vec![0u8; stride];
+            for row in 0..pass_h {
+                let filter = *raw.get(offset).ok_or(DecodeError::Data)?;
+                let mut line = raw.get(offset + 1..offset + 1 + stride).ok_or(DecodeError::Data)?.to_vec();
+                offset += 1 + stride;
+                _unfilter(filter, &mut line, &previous, step).ok_or(DecodeError::Data)?;
+
+                for column in 0..pass_w {
+                    let brightness = _brightness(&line, column, depth, color, palette, transparency, if invert { 0 } else { 255 });
+                    if (brightness < threshold) != invert {
+                        bitmap.set_pixel(x0 + column * dx, y0 + row * dy, true);
+                    }
+                }
+                previous = line;
+            }
+        }
+        return Ok(bitmap);
+    }
+}
+
+/// Reverse the filter of a line of image data.
+fn _unfilter(filter: u8, line: &mut [u8], previous: &[u8], step: usize) -> Option<()> {
+    for i in 0..line.len() {
+        let left = if i >= step { line[i - step] } else { 0 };
+        let up = previous[i];
+        let up_left = if i >= step { previous[i - step] } else { 0 };
+        line[i] = line[i].wrapping_add(match filter {
+            0 => 0,
+            1 => left,
+            2 => up,
+            3 => ((left as u16 + up as u16) / 2) as u8,
+            4 => {
+                let estimate = left as i16 + up as i16 - up_left as i16;
+                let (a, b, c) = ((estimate - left as i16).abs(), (estimate - up as i16).abs(), (estimate - up_left as i16).abs());
+                if a <= b && a <= c { left } else if b <= c { up } else { up_left }
+            }
+            _ => { return None; }
+        });
+    }
+    return Some(());
+}
+
+/// Brightness of a pixel from 0 to 255, blended over the background by its
+/// transparency.
+fn _brightness(line: &[u8], column: usize, depth: usize, color: u8, palette: &[u8], transparency: &[u8], background: u8) -> u8 {
+    let channels = match color { 2 => 3, 4 => 2, 6 => 4, _ => 1 };
+    let sample = |channel: usize| -> (usize, u8) {
+        let index = column * channels + channel;
+        if depth == 16 {
+            return ((line[index * 2] as usize) << 8 | line[index * 2 + 1] as usize, line[index * 2]);
+        }
+        let bit = index * depth;
+        let value = (line[bit / 8] >> (8 - depth - bit % 8)) as usize & ((1 << depth) - 1);
+        return (value, (value * 255 / ((1 << depth) - 1)) as u8);
+    };
+    let key = |channel: usize| -> usize {
+        return transparency.get(channel * 2..channel * 2 + 2).map(|v| (v[0] as usize) << 8 | v[1] as usize).unwrap_or(usize::MAX);
+    };
+
+    let (value, alpha) = match color {
+        0 => {
+            let (raw, gray) = sample(0);
+            (gray, if raw == key(0) { 0 } else { 255 })
+        }
+        2 => {
+            let ((r_raw, r), (g_raw, g), (b_raw, b)) = (sample(0), sample(1), sample(2));
+            (_luminance(r, g, b), if (r_raw, g_raw, b_raw) == (key(0), key(1), key(2)) { 0 } else { 255 })
+        }
+        3 => {
+            let (index, _) = sample(0);
+            let rgb = palette.get(index * 3..index * 3 + 3).unwrap_or(&[0, 0, 0]);
+            (_luminance(rgb[0], rgb[1], rgb[2]), transparency.get(index).copied().unwrap_or(255))
+        }
+        4 => (sample(0).1, sample(1).1),
+        _ => (_luminance(sample(0).1, sample(1).1, sample(2).1), sample(3).1)
+    };
+    return ((value as u32 * alpha as u32 + background as u32 * (255 - alpha as u32)) / 255) as u8;
+}
+
+/// Append a chunk with length, type, data and CRC.
+fn _chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = _crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wrap data into a zlib stream of uncompressed deflate blocks.
+fn _zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = Vec::with_capacity(data.len() + data.len() / STORED_BLOCK * 5 + 11);
+    zlib.extend_from_slice(&[0x78, 0x01]);
+    let mut blocks = data.chunks(STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let length = block.len() as u16;
+        zlib.push(if blocks.peek().is_none() { 1 } else { 0 });
+        zlib.extend_from_slice(&length.to_le_bytes());
+        zlib.extend_from_slice(&(!length).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&_adler32(data).to_be_bytes());
+    return zlib;
+}
+
+pub(crate) fn _crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in data {
+        crc = CRC_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    return !crc;
+}
+
+pub(crate) fn _adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for byte in chunk {
+            a += *byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    return (b << 16) | a;
+}
+
+const fn _crc_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 { 0xedb8_8320 ^ (c >> 1) } else { c >> 1 };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    return table;
+}
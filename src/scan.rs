@@ -0,0 +1,158 @@
+use crate::bitmap::Bitmap;
+use crate::sink::{Sink, _write_to};
+use crate::writer::{Write, WriteResult, Writer};
+
+/// Write the bitmaps found at successive offsets of a block of data, each
+/// below a line with its offset, to locate images like splash screens or
+/// fonts inside firmware images and memory dumps.
+///
+/// Every offset is interpreted as a bitmap of the given size with the
+/// writer's settings. By default the offsets follow each other at the
+/// length of one bitmap, see `Writer::data_len(..)`, so the data is shown
+/// completely. Once the rough position is known, a smaller step aligns the
+/// image.
+///
+/// ```
+/// use bitmap_writer::{Scan, Style, Writer};
+///
+/// let data = [0x80, 0x40, 0x20, 0x10, 0x08];
+/// let mut w = Writer::new();
+/// w.style(Style::ASCII1x1('#'));
+///
+/// // Bitmaps of 8x2 pixels are 2 bytes long, the last byte is no complete bitmap.
+/// let mut text = String::new();
+/// Scan::new(&w, &data, 8, 2).write(&mut text);
+/// assert_eq!(text, "0x00000000:\n#       \n #      \n0x00000002:\n  #     \n   #    \n");
+///
+/// let mut text = String::new();
+/// Scan::new(&w, &data, 8, 2).start(1).step(Some(1)).count(Some(2)).base(0x8000).write(&mut text);
+/// assert_eq!(text, "0x00008001:\n #      \n  #     \n0x00008002:\n  #     \n   #    \n");
+///
+/// // Without a count, the scan stops at the last complete bitmap, at offset 3.
+/// let mut text = String::new();
+/// Scan::new(&w, &data, 8, 2).start(1).step(Some(1)).write(&mut text);
+/// assert_eq!(text.lines().filter(|line| line.starts_with("0x")).collect::<Vec<_>>(), ["0x00000001:", "0x00000002:", "0x00000003:"]);
+/// ```
+pub struct Scan<'a> {
+    _writer: &'a Writer<'a>,
+    _data: &'a [u8],
+    _width: usize,
+    _height: usize,
+    _start: usize,
+    _step: Option<usize>,
+    _count: Option<usize>,
+    _base: u64
+}
+
+impl<'a> Scan<'a> {
+    /// Construct a scan over all of the data, one bitmap after the other.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to read and write each bitmap.
+    /// - `data`: The data to scan.
+    /// - `width`: Pixel width of each bitmap.
+    /// - `height`: Pixel height of each bitmap.
+    ///
+    /// # Returns
+    /// - Scan instance.
+    pub fn new(writer: &'a Writer<'a>, data: &'a [u8], width: usize, height: usize) -> Scan<'a> {
+        return Scan { _writer: writer, _data: data, _width: width, _height: height, _start: 0, _step: None, _count: None, _base: 0 };
+    }
+
+    /// Set the offset of the first bitmap.
+    ///
+    /// # Arguments
+    /// - `offset`: Offset into the data in bytes.
+    ///
+    /// # Returns
+    /// - Reference to scan.
+    pub fn start(&mut self, offset: usize) -> &mut Self {
+        self._start = offset;
+        return self;
+    }
+
+    /// Set the distance between the offsets of two bitmaps.
+    ///
+    /// # Arguments
+    /// - `step`: Distance in bytes, at least 1, `None` for the length of one bitmap.
+    ///
+    /// # Returns
+    /// - Reference to scan.
+    pub fn step(&mut self, step: Option<usize>) -> &mut Self {
+        self._step = step.map(|s| s.max(1));
+        return self;
+    }
+
+    /// Limit the number of bitmaps written. Otherwise the scan continues as
+    /// long as a complete bitmap fits into the data. Bitmaps without pixels
+    /// are never written.
+    ///
+    /// # Arguments
+    /// - `count`: Maximum number of bitmaps, `None` for no limit.
+    ///
+    /// # Returns
+    /// - Reference to scan.
+    pub fn count(&mut self, count: Option<usize>) -> &mut Self {
+        self._count = count;
+        return self;
+    }
+
+    /// Set the offset of the data itself, which is added to the offsets
+    /// written, e.g. when the data was read from the middle of a file.
+    ///
+    /// # Arguments
+    /// - `base`: Offset of the first byte of the data.
+    ///
+    /// # Returns
+    /// - Reference to scan.
+    pub fn base(&mut self, base: u64) -> &mut Self {
+        self._base = base;
+        return self;
+    }
+
+    /// Write the offsets and bitmaps.
+    ///
+    /// # Arguments
+    /// - `writer`: A sink implementing `core::fmt::Write` or `std::io::Write`, see `bitmap_writer::Sink`. It will contain the result of the bitmap conversions.
+    pub fn write<K>(&self, writer: &mut impl Sink<K>) {
+        _write_to(writer, |w| self._write_scan(w));
+    }
+
+    fn _write_scan(&self, w: &mut impl Write) -> WriteResult {
+        let length = self._writer.data_len(self._width, self._height);
+        let step = self._step.unwrap_or(length.max(1));
+        let mut offset = self._start;
+        let mut written = 0;
+        while length > 0 && offset + length <= self._data.len() && self._count.is_none_or(|count| written < count) {
+            writeln!(w, "0x{:08x}:", self._base + offset as u64)?;
+            self._writer._write_bitmap(w, &Bitmap::new(self._width, self._height, &self._data[offset..offset + length]))?;
+            offset += step;
+            written += 1;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "std")]
+use crate::sink::_print_to;
+
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+impl Scan<'_> {
+    /// Print the offsets and bitmaps out to terminal on **stdout**, ignoring
+    /// errors.
+    pub fn print(&self) {
+        let _ = self.print_to(&mut std::io::stdout().lock());
+    }
+
+    /// Print the offsets and bitmaps to any stream, see `Writer::print_to(..)`.
+    ///
+    /// # Arguments
+    /// - `stream`: The stream to write to.
+    ///
+    /// # Returns
+    /// - The error of the stream, apart from a broken pipe.
+    pub fn print_to(&self, stream: &mut impl std::io::Write) -> std::io::Result<()> {
+        return _print_to(stream, |w| self._write_scan(w));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::source::Image;
+use crate::writer::{WriteResult, Writer};
+
+use core::marker::PhantomData;
+
+/// Marker for sinks implementing `core::fmt::Write`, see `Sink`.
+pub enum Fmt {}
+
+/// Marker for sinks implementing `std::io::Write`, see `Sink`.
+///
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+pub enum Io {}
+
+/// Destination of the text written by `Writer::write(..)` and the other
+/// outputs: anything implementing `core::fmt::Write`, like a `String` or a
+/// `core::fmt::Formatter`, and with the `std` feature also anything
+/// implementing `std::io::Write`, like a file, a `Vec<u8>` or stdout.
+///
+/// The type parameter only tells the two kinds apart and is inferred, so
+/// sinks are passed as they are. Sinks implementing both traits need it
+/// spelled out, e.g. `Sink<Fmt>`. The trait is sealed, implement one of
+/// the two write traits instead.
+///
+/// ```
+/// use core::fmt::Write;
+///
+/// let bitmap = bitmap_writer::Bitmap::new(8, 2, &[0b00111100, 0b01000010]);
+/// let w = bitmap_writer::Writer::new();
+///
+/// let mut text = String::new();
+/// w.write(&mut text, &bitmap);
+/// writeln!(text, "{}", w.display(&bitmap)).unwrap();
+/// ```
+pub trait Sink<Kind>: private::Sealed<Kind> {
+    #[doc(hidden)]
+    fn _write_text(&mut self, text: &str) -> WriteResult;
+
+    #[doc(hidden)]
+    fn _flush(&mut self) -> WriteResult;
+}
+
+mod private {
+    pub trait Sealed<Kind> {}
+
+    impl<W: core::fmt::Write + ?Sized> Sealed<super::Fmt> for W {}
+
+    #[cfg(feature = "std")]
+    impl<W: std::io::Write + ?Sized> Sealed<super::Io> for W {}
+}
+
+impl<W: core::fmt::Write + ?Sized> Sink<Fmt> for W {
+    fn _write_text(&mut self, text: &str) -> WriteResult {
+        return self.write_str(text);
+    }
+
+    fn _flush(&mut self) -> WriteResult {
+        return Ok(());
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> Sink<Io> for W {
+    fn _write_text(&mut self, text: &str) -> WriteResult {
+        return self.write_all(text.as_bytes()).map_err(|_| core::fmt::Error);
+    }
+
+    fn _flush(&mut self) -> WriteResult {
+        return self.flush().map_err(|_| core::fmt::Error);
+    }
+}
+
+/// Passes the text rendered through `core::fmt::Write` on to a sink.
+pub(crate) struct SinkWriter<'s, K, S: Sink<K> + ?Sized> {
+    _sink: &'s mut S,
+    _kind: PhantomData<fn() -> K>
+}
+
+impl<'s, K, S: Sink<K> + ?Sized> SinkWriter<'s, K, S> {
+    pub(crate) fn new(sink: &'s mut S) -> SinkWriter<'s, K, S> {
+        return SinkWriter { _sink: sink, _kind: PhantomData };
+    }
+
+    pub(crate) fn _flush(&mut self) -> WriteResult {
+        return self._sink._flush();
+    }
+}
+
+impl<K, S: Sink<K> + ?Sized> core::fmt::Write for SinkWriter<'_, K, S> {
+    fn write_str(&mut self, s: &str) -> WriteResult {
+        return self._sink._write_text(s);
+    }
+}
+
+/// Write text to a sink and flush it, ignoring errors, see `Writer::write(..)`.
+pub(crate) fn _write_to<K, S: Sink<K> + ?Sized>(sink: &mut S, write: impl FnOnce(&mut SinkWriter<'_, K, S>) -> WriteResult) {
+    let mut w = SinkWriter::new(sink);
+    let _ = write(&mut w).and_then(|_| w._flush());
+}
+
+/// Passes the text rendered through `core::fmt::Write` on to a stream,
+/// keeping the error of the stream, which `core::fmt::Error` cannot hold.
+#[cfg(feature = "std")]
+pub(crate) struct IoWriter<W: std::io::Write> {
+    _inner: W,
+    _error: Option<std::io::Error>
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoWriter<W> {
+    pub(crate) fn new(inner: W) -> IoWriter<W> {
+        return IoWriter { _inner: inner, _error: None };
+    }
+
+    /// Flush the stream and get the first error. A broken pipe, when the
+    /// reading end was closed, e.g. by `head`, is not an error.
+    pub(crate) fn _finish(mut self, result: WriteResult) -> std::io::Result<()> {
+        let result = match (result, self._error.take()) {
+            (_, Some(error)) => Err(error),
+            (Err(_), None) => Err(std::io::Error::other("formatting failed")),
+            (Ok(()), None) => self._inner.flush()
+        };
+        return match result {
+            Err(error) if error.kind() == std::io::ErrorKind::BrokenPipe => Ok(()),
+            result => result
+        };
+    }
+}
+
+/// Write text to a stream through a buffer, see `Writer::print_to(..)`.
+#[cfg(feature = "std")]
+pub(crate) fn _print_to<S: std::io::Write>(stream: &mut S, write: impl FnOnce(&mut IoWriter<std::io::BufWriter<&mut S>>) -> WriteResult) -> std::io::Result<()> {
+    let mut w = IoWriter::new(std::io::BufWriter::new(stream));
+    let result = write(&mut w);
+    return w._finish(result);
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> core::fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> WriteResult {
+        return self._inner.write_all(s.as_bytes()).map_err(|error| {
+            self._error = Some(error);
+            return core::fmt::Error;
+        });
+    }
+}
+
+/// ```no_std``` compatible and without allocation.
+impl<'a> Writer<'a> {
+    /// Wrap the bitmap for formatting with `{}`, e.g. in `format!(..)`,
+    /// `println!(..)` or `write!(..)`, exactly as `write(..)` would write it.
+    ///
+    /// ```
+    /// let bitmap = bitmap_writer::Bitmap::new(4, 2, &[0b10010110]);
+    /// let mut w = bitmap_writer::Writer::new();
+    /// w.style(bitmap_writer::Style::ASCII1x1('#'));
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "#  #\n ## \n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to write.
+    ///
+    /// # Returns
+    /// - Value implementing `core::fmt::Display`.
+    pub fn display<'b, I: Image + ?Sized>(&'b self, bitmap: &'b I) -> impl core::fmt::Display + 'b {
+        return DisplayBitmap { _writer: self, _bitmap: bitmap };
+    }
+}
+
+struct DisplayBitmap<'b, I: Image + ?Sized> {
+    _writer: &'b Writer<'b>,
+    _bitmap: &'b I
+}
+
+impl<I: Image + ?Sized> core::fmt::Display for DisplayBitmap<'_, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return self._writer._write_bitmap(f, self._bitmap);
+    }
+}
@@ -0,0 +1,215 @@
+use core::cell::RefCell;
+use crate::bitmap::Bitmap;
+use crate::writer::Writer;
+
+/// Pixels that are read one at a time instead of from a slice of bytes, for
+/// example from memory-mapped peripherals or generated on the fly.
+///
+/// Pass a source instead of a `Bitmap` to the text outputs of a `Writer`,
+/// like `write(..)`, `print(..)`, `display(..)` or `chars(..)`, see
+/// `bitmap_writer::Image`. As the pixels are already decoded, the writer's
+/// `be(..)` and `byte_aligned(..)` settings do not apply, while all other
+/// settings do. For the other outputs, copy the source with
+/// `Writer::to_bitmap(..)` first.
+///
+/// ```
+/// use bitmap_writer::{PixelSource, Style, Writer};
+///
+/// /// A checkerboard of 4x4 squares, generated without storing any pixels.
+/// struct Checkerboard;
+///
+/// impl PixelSource for Checkerboard {
+///     fn width(&self) -> usize { 32 }
+///     fn height(&self) -> usize { 16 }
+///     fn pixel(&self, x: usize, y: usize) -> bool { (x / 4 + y / 4) % 2 == 0 }
+/// }
+///
+/// let mut text = String::new();
+/// Writer::new().style(Style::ASCII1x1('#')).write(&mut text, &Checkerboard);
+/// assert!(text.starts_with("####    ####    "));
+/// assert_eq!(text.lines().count(), 16);
+/// ```
+pub trait PixelSource {
+    /// Get the source's width.
+    ///
+    /// # Returns
+    /// - Pixel width.
+    fn width(&self) -> usize;
+
+    /// Get the source's height.
+    ///
+    /// # Returns
+    /// - Pixel height.
+    fn height(&self) -> usize;
+
+    /// Get whether a pixel is set. Only called for pixels within width and
+    /// height.
+    ///
+    /// # Arguments
+    /// - `x`: Pixel column.
+    /// - `y`: Pixel row.
+    ///
+    /// # Returns
+    /// - `true` if the pixel is set.
+    fn pixel(&self, x: usize, y: usize) -> bool;
+}
+
+/// Pixels that are read a row at a time, like the readback of a display
+/// over SPI or lines of a ring buffer. See `RowBuffer` to write them.
+pub trait RowSource {
+    /// Get the source's width.
+    ///
+    /// # Returns
+    /// - Pixel width.
+    fn width(&self) -> usize;
+
+    /// Get the source's height.
+    ///
+    /// # Returns
+    /// - Pixel height.
+    fn height(&self) -> usize;
+
+    /// Read a row of pixels, 8 per byte with the first pixel in the most
+    /// significant bit. Only called for rows within the height.
+    ///
+    /// # Arguments
+    /// - `y`: Pixel row.
+    /// - `row`: Buffer to fill, `width().div_ceil(8)` bytes long unless the `RowBuffer` is too short.
+    fn read_row(&mut self, y: usize, row: &mut [u8]);
+}
+
+/// Pixels a `Writer` can write: a `Bitmap`, decoded with the writer's
+/// `be(..)` and `byte_aligned(..)` settings, or any `PixelSource`.
+///
+/// The trait is sealed, implement `PixelSource` instead.
+pub trait Image: private::Sealed {
+    #[doc(hidden)]
+    fn _dimensions(&self) -> (usize, usize);
+
+    #[doc(hidden)]
+    fn _pixel(&self, writer: &Writer, x: usize, y: usize) -> bool;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::bitmap::Bitmap<'_> {}
+
+    impl<S: super::PixelSource + ?Sized> Sealed for S {}
+}
+
+impl Image for Bitmap<'_> {
+    fn _dimensions(&self) -> (usize, usize) {
+        return (self._w, self._h);
+    }
+
+    fn _pixel(&self, writer: &Writer, x: usize, y: usize) -> bool {
+        return writer._pixel(self, x, y);
+    }
+}
+
+impl<S: PixelSource + ?Sized> Image for S {
+    fn _dimensions(&self) -> (usize, usize) {
+        return (self.width(), self.height());
+    }
+
+    fn _pixel(&self, _writer: &Writer, x: usize, y: usize) -> bool {
+        return x < self.width() && y < self.height() && self.pixel(x, y);
+    }
+}
+
+/// Number of rows a `RowBuffer` keeps, enough for the tallest characters of
+/// all styles, so every row is read once per text line.
+const ROWS: usize = 3;
+
+/// Turns a `RowSource` into a `PixelSource` by keeping the last few rows
+/// read in a fixed buffer of `BYTES` bytes per row, without allocating.
+///
+/// Writing a bitmap reads its rows in order, text line by text line, so
+/// each row is only read once. Rotation, transposing and scaling down read
+/// rows out of order and then read rows more than once, but still work.
+///
+/// ```
+/// use bitmap_writer::{RowBuffer, RowSource, Writer};
+///
+/// /// A display of 128x64 pixels, read back a row at a time.
+/// struct Display;
+///
+/// impl RowSource for Display {
+///     fn width(&self) -> usize { 128 }
+///     fn height(&self) -> usize { 64 }
+///     fn read_row(&mut self, y: usize, row: &mut [u8]) { row.fill(if y % 8 == 0 { 0xff } else { 0x80 }); }
+/// }
+///
+/// // 3 rows of 16 bytes, 48 bytes of RAM.
+/// let source = RowBuffer::<_, 16>::new(Display);
+/// let text = format!("{}", Writer::new().display(&source));
+/// assert_eq!(text.lines().count(), 32);
+/// ```
+pub struct RowBuffer<S: RowSource, const BYTES: usize> {
+    _source: RefCell<S>,
+    _rows: RefCell<[(Option<usize>, [u8; BYTES]); ROWS]>
+}
+
+impl<S: RowSource, const BYTES: usize> RowBuffer<S, BYTES> {
+    /// Construct a row buffer. Pixels beyond the first `BYTES * 8` of a
+    /// row are unset, so `BYTES` should be at least the source's width
+    /// divided by 8.
+    ///
+    /// # Arguments
+    /// - `source`: The source to read the rows from.
+    ///
+    /// # Returns
+    /// - RowBuffer instance.
+    pub fn new(source: S) -> RowBuffer<S, BYTES> {
+        return RowBuffer { _source: RefCell::new(source), _rows: RefCell::new([(None, [0; BYTES]); ROWS]) };
+    }
+
+    /// Get the source back, e.g. to release a bus.
+    ///
+    /// # Returns
+    /// - The source.
+    pub fn into_inner(self) -> S {
+        return self._source.into_inner();
+    }
+}
+
+impl<S: RowSource, const BYTES: usize> PixelSource for RowBuffer<S, BYTES> {
+    fn width(&self) -> usize {
+        return self._source.borrow().width();
+    }
+
+    fn height(&self) -> usize {
+        return self._source.borrow().height();
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> bool {
+        if x >= BYTES * 8 { return false; }
+        let mut rows = self._rows.borrow_mut();
+        let (cached, row) = &mut rows[y % ROWS];
+        if *cached != Some(y) {
+            let mut source = self._source.borrow_mut();
+            let length = source.width().div_ceil(8).min(BYTES);
+            row.fill(0);
+            source.read_row(y, &mut row[..length]);
+            *cached = Some(y);
+        }
+        return row[x / 8] & (0x80 >> (x % 8)) != 0;
+    }
+}
+
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+#[cfg(feature = "alloc")]
+impl PixelSource for crate::bitmap::BitmapBuf {
+    fn width(&self) -> usize {
+        return self._w;
+    }
+
+    fn height(&self) -> usize {
+        return self._h;
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> bool {
+        return crate::bitmap::BitmapBuf::pixel(self, x, y);
+    }
+}
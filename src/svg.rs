@@ -0,0 +1,187 @@
+use crate::bitmap::Bitmap;
+use crate::sink::{Sink, _write_to};
+use crate::writer::{Write, WriteResult, Writer};
+
+/// Write a bitmap as scalable vector graphics.
+///
+/// Pixels are read as the given writer sees them, so endianess,
+/// byte-alignment, flipping, rotation, scaling and the viewport apply, while
+/// style, frame and the other text settings are ignored. Each horizontal run
+/// of set pixels becomes one rectangle of a single merged path, which keeps
+/// the file small and the edges crisp.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, Svg, Writer};
+///
+/// let bitmap = Bitmap::new(8, 2, &[0b11110000, 0b00001111]);
+/// let w = Writer::new();
+/// let mut svg = Svg::new(&w, &bitmap);
+/// svg.pixel_size(4).colors("#222", Some("#eee")).grid(Some("#ccc"));
+/// ```
+pub struct Svg<'a> {
+    _writer: &'a Writer<'a>,
+    _bitmap: &'a Bitmap<'a>,
+    _pixel_size: usize,
+    _foreground: &'a str,
+    _background: Option<&'a str>,
+    _grid: Option<&'a str>
+}
+
+impl<'a> Svg<'a> {
+    /// Construct an SVG writer for a bitmap with black pixels of 8x8 units on
+    /// a transparent background and without grid.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer used to read the bitmap.
+    /// - `bitmap`: The bitmap to write.
+    ///
+    /// # Returns
+    /// - Svg instance.
+    pub fn new(writer: &'a Writer<'a>, bitmap: &'a Bitmap<'a>) -> Svg<'a> {
+        return Svg { _writer: writer, _bitmap: bitmap, _pixel_size: 8, _foreground: "#000", _background: None, _grid: None };
+    }
+
+    /// Set the width and height of a single pixel in the SVG's units.
+    ///
+    /// # Arguments
+    /// - `size`: Size of a pixel, at least 1.
+    ///
+    /// # Returns
+    /// - Reference to svg.
+    pub fn pixel_size(&mut self, size: usize) -> &mut Self {
+        self._pixel_size = size.max(1);
+        return self;
+    }
+
+    /// Set the colors, as any color value understood by SVG, e.g. `#000` or
+    /// `black`. The colors are escaped, so they cannot break out of the
+    /// attribute.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Svg, Writer};
+    ///
+    /// let bitmap = Bitmap::new(2, 1, &[0b10000000]);
+    /// let w = Writer::new();
+    /// let mut text = String::new();
+    /// Svg::new(&w, &bitmap).pixel_size(1).colors("red\"/><script>", Some("#fff")).write(&mut text);
+    /// assert_eq!(text, concat!(
+    ///     "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"2\" height=\"1\" viewBox=\"0 0 2 1\" shape-rendering=\"crispEdges\">\n",
+    ///     "<rect width=\"2\" height=\"1\" fill=\"#fff\"/>\n",
+    ///     "<path fill=\"red&quot;/>&lt;script>\" d=\"M0 0h1v1h-1z\"/>\n",
+    ///     "</svg>\n"
+    /// ));
+    /// ```
+    ///
+    /// # Arguments
+    /// - `foreground`: Color of set pixels.
+    /// - `background`: Color of unset pixels, `None` for transparent.
+    ///
+    /// # Returns
+    /// - Reference to svg.
+    pub fn colors(&mut self, foreground: &'a str, background: Option<&'a str>) -> &mut Self {
+        self._foreground = foreground;
+        self._background = background;
+        return self;
+    }
+
+    /// Draw a one unit wide grid between the pixels.
+    ///
+    /// # Arguments
+    /// - `color`: Color of the grid, `None` for no grid.
+    ///
+    /// # Returns
+    /// - Reference to svg.
+    pub fn grid(&mut self, color: Option<&'a str>) -> &mut Self {
+        self._grid = color;
+        return self;
+    }
+
+    /// Write the SVG document.
+    ///
+    /// # Arguments
+    /// - `writer`: A sink implementing `core::fmt::Write` or `std::io::Write`, see `bitmap_writer::Sink`. It will contain the resulting SVG.
+    pub fn write<K>(&self, writer: &mut impl Sink<K>) {
+        _write_to(writer, |w| self._write_svg(w));
+    }
+
+    fn _write_svg(&self, w: &mut impl Write) -> WriteResult {
+        let (left, top, width, height) = self._writer._region(self._bitmap);
+        let size = self._pixel_size;
+
+        writeln!(
+            w, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">",
+            width * size, height * size, width, height
+        )?;
+        if let Some(background) = self._background {
+            write!(w, "<rect width=\"{}\" height=\"{}\" fill=\"", width, height)?;
+            _write_attribute(w, background)?;
+            writeln!(w, "\"/>")?;
+        }
+
+        write!(w, "<path fill=\"")?;
+        _write_attribute(w, self._foreground)?;
+        write!(w, "\" d=\"")?;
+        for y in 0..height {
+            let mut x = 0;
+            while x < width {
+                if !self._writer._sample(self._bitmap, left + x, top + y) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < width && self._writer._sample(self._bitmap, left + x, top + y) { x += 1; }
+                write!(w, "M{} {}h{}v1h-{}z", start, y, x - start, x - start)?;
+            }
+        }
+        writeln!(w, "\"/>")?;
+
+        if let Some(grid) = self._grid {
+            write!(w, "<path fill=\"none\" stroke=\"")?;
+            _write_attribute(w, grid)?;
+            write!(w, "\" stroke-width=\"{}\" d=\"", 1.0 / size as f32)?;
+            for x in 1..width { write!(w, "M{} 0v{}", x, height)?; }
+            for y in 1..height { write!(w, "M0 {}h{}", y, width)?; }
+            writeln!(w, "\"/>")?;
+        }
+
+        writeln!(w, "</svg>")?;
+        return Ok(());
+    }
+}
+
+/// Write the value of an attribute in double quotes, escaping the
+/// characters that would end the attribute or start markup.
+fn _write_attribute(w: &mut impl Write, value: &str) -> WriteResult {
+    for c in value.chars() {
+        match c {
+            '"' => { write!(w, "&quot;")?; }
+            '&' => { write!(w, "&amp;")?; }
+            '<' => { write!(w, "&lt;")?; }
+            _ => { write!(w, "{}", c)?; }
+        }
+    }
+    return Ok(());
+}
+
+#[cfg(feature = "std")]
+use crate::sink::_print_to;
+
+/// Requires `features=["std"]` in ```cargo.toml```.
+#[cfg(feature = "std")]
+impl Svg<'_> {
+    /// Print the SVG document out to terminal on **stdout**, ignoring errors.
+    pub fn print(&self) {
+        let _ = self.print_to(&mut std::io::stdout().lock());
+    }
+
+    /// Print the SVG document to any stream, see `Writer::print_to(..)`.
+    ///
+    /// # Arguments
+    /// - `stream`: The stream to write to.
+    ///
+    /// # Returns
+    /// - The error of the stream, apart from a broken pipe.
+    pub fn print_to(&self, stream: &mut impl std::io::Write) -> std::io::Result<()> {
+        return _print_to(stream, |w| self._write_svg(w));
+    }
+}
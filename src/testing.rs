@@ -0,0 +1,217 @@
+use crate::{Frame, Layout, Panel, Style};
+use crate::bitmap::BitmapBuf;
+use crate::source::Image;
+use crate::parse::ParseError;
+use crate::writer::{Writer, _Buffer};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// Convert ASCII art into a bitmap.
+///
+/// `#` is a set pixel, `.` and spaces are unset pixels. Empty lines at the
+/// start and end are skipped and the indentation common to all other lines
+/// is removed, so the art can be indented like the surrounding code. Lines
+/// shorter than the longest line are filled with unset pixels.
+///
+/// ```
+/// let bitmap = bitmap_writer::testing::parse_ascii_art("
+///     .##.
+///     #..#
+/// ").unwrap();
+/// assert_eq!((bitmap.width(), bitmap.height()), (4, 2));
+/// assert!(bitmap.pixel(1, 0) && !bitmap.pixel(1, 1));
+/// ```
+///
+/// # Arguments
+/// - `text`: The ASCII art.
+///
+/// # Returns
+/// - The bitmap, or the position of the first character that is neither `#` nor `.`.
+pub fn parse_ascii_art(text: &str) -> Result<BitmapBuf, ParseError> {
+    let lines: Vec<(usize, &str)> = text.lines().enumerate().collect();
+    let first = lines.iter().position(|(_, l)| !l.trim().is_empty()).unwrap_or(lines.len());
+    let last = lines.iter().rposition(|(_, l)| !l.trim().is_empty()).map(|i| i + 1).unwrap_or(first);
+    let lines = &lines[first..last];
+
+    let indent = lines.iter()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(_, l)| l.chars().take_while(|c| c.is_whitespace()).count())
+        .min()
+        .unwrap_or(0);
+    let width = lines.iter().map(|(_, l)| l.trim_end().chars().count().saturating_sub(indent)).max().unwrap_or(0);
+
+    let mut bitmap = BitmapBuf::new(width, lines.len());
+    for (y, (line, text)) in lines.iter().enumerate() {
+        for (x, glyph) in text.trim_end().chars().skip(indent).enumerate() {
+            match glyph {
+                '#' => { bitmap.set_pixel(x, y, true); }
+                '.' | ' ' => { }
+                _ => { return Err(ParseError::UnknownGlyph { line: line + 1, column: indent + x + 1, glyph }); }
+            }
+        }
+    }
+    return Ok(bitmap);
+}
+
+/// Convert a bitmap into ASCII art, the inverse of `parse_ascii_art(..)`.
+///
+/// Every line holds one row of pixels, `#` for set and `.` for unset pixels,
+/// and ends with a newline. The result is stable and easy to review, which
+/// makes it suitable as a snapshot, for example with
+/// `insta::assert_snapshot!(ascii_art(&bitmap))`.
+///
+/// The bitmap is read with a writer's default settings, use
+/// `Writer::to_bitmap(..)` first for big-endian or byte-aligned bitmaps.
+///
+/// ```
+/// use bitmap_writer::{Bitmap, testing::{ascii_art, parse_ascii_art}};
+///
+/// let bitmap = Bitmap::new(4, 2, &[0b0110_1001]);
+/// assert_eq!(ascii_art(&bitmap), ".##.\n#..#\n");
+///
+/// let owned = parse_ascii_art(".##.\n#..#").unwrap();
+/// assert_eq!(ascii_art(&owned), ".##.\n#..#\n");
+/// ```
+///
+/// # Arguments
+/// - `bitmap`: The bitmap to convert.
+///
+/// # Returns
+/// - The ASCII art.
+pub fn ascii_art(bitmap: &(impl Image + ?Sized)) -> String {
+    let writer = Writer::new();
+    let (width, height) = writer._size(bitmap);
+    let mut text = String::with_capacity((width + 1) * height);
+    for y in 0..height {
+        for x in 0..width {
+            text.push(if writer._sample(bitmap, x, y) { '#' } else { '.' });
+        }
+        text.push('\n');
+    }
+    return text;
+}
+
+/// Assert that a bitmap matches ASCII art, see `parse_ascii_art(..)`.
+///
+/// The bitmap is read as the writer given as optional third argument sees it,
+/// see `Writer::to_bitmap(..)`, or with default settings otherwise. On
+/// failure, the panic message lists the sizes and the number of differing
+/// pixels, followed by expected, actual and the differing pixels side-by-side.
+///
+/// Requires `features=["testing"]` in ```cargo.toml```.
+///
+/// ```
+/// use bitmap_writer::{assert_bitmap_eq, Bitmap, Writer};
+///
+/// let bitmap = Bitmap::new(4, 2, &[0b0110_1001]);
+/// assert_bitmap_eq!(bitmap, "
+///     .##.
+///     #..#
+/// ");
+///
+/// let aligned = Bitmap::new(4, 2, &[0b0110_0000, 0b1001_0000]);
+/// assert_bitmap_eq!(aligned, "
+///     .##.
+///     #..#
+/// ", Writer::new().byte_aligned(true));
+/// ```
+///
+/// Owned bitmaps, e.g. from `BitmapBuf::from_png(..)` or
+/// `Writer::parse(..)`, and any `PixelSource` are compared as they are:
+///
+/// ```should_panic
+/// use bitmap_writer::{assert_bitmap_eq, BitmapBuf};
+///
+/// let mut bitmap = BitmapBuf::new(3, 2);
+/// bitmap.set_pixel(0, 0, true);
+/// bitmap.set_pixel(2, 1, true);
+/// assert_bitmap_eq!(bitmap, "
+///     #..#
+///     ..#.
+/// ");
+/// ```
+///
+/// The panic message of the failed assertion above is:
+///
+/// ```
+/// # use bitmap_writer::{assert_bitmap_eq, BitmapBuf};
+/// # let mut bitmap = BitmapBuf::new(3, 2);
+/// # bitmap.set_pixel(0, 0, true);
+/// # bitmap.set_pixel(2, 1, true);
+/// # let panic = std::panic::catch_unwind(|| assert_bitmap_eq!(bitmap, "#..#\n..#.")).unwrap_err();
+/// # let message = panic.downcast_ref::<String>().unwrap();
+/// assert_eq!(message, "\
+/// assertion failed: bitmaps differ
+///   expected: 4x2
+///     actual: 3x2
+///    differs: 1 pixels
+///
+/// expected    actual      diff
+/// .----.      .---.       .----.  
+/// |#  #|      |#  |       |   X|  
+/// |  # |      |  #|       |    |  
+/// '----'      '---'       '----'  
+/// ");
+/// ```
+#[macro_export]
+macro_rules! assert_bitmap_eq {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::testing::_assert_bitmap_eq(&$crate::Writer::new(), &$actual, $expected)
+    };
+    ($actual:expr, $expected:expr, $writer:expr $(,)?) => {
+        $crate::testing::_assert_bitmap_eq(&$writer, &$actual, $expected)
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn _assert_bitmap_eq(writer: &Writer, actual: &(impl Image + ?Sized), expected: &str) {
+    let expected = match parse_ascii_art(expected) {
+        Ok(expected) => expected,
+        Err(e) => { panic!("assertion failed: expected bitmap is invalid: {}", e); }
+    };
+    let actual = writer.to_bitmap(actual);
+    if actual == expected { return; }
+
+    let width = actual.width().max(expected.width());
+    let height = actual.height().max(expected.height());
+    let mut diff = BitmapBuf::new(width, height);
+    let mut count = 0;
+    for y in 0..height {
+        for x in 0..width {
+            if _get(&actual, x, y) != _get(&expected, x, y) {
+                diff.set_pixel(x, y, true);
+                count += 1;
+            }
+        }
+    }
+
+    let mut message = String::new();
+    let _ = write!(
+        message, "assertion failed: bitmaps differ\n  expected: {}x{}\n    actual: {}x{}\n   differs: {} pixels\n\n",
+        expected.width(), expected.height(), actual.width(), actual.height(), count
+    );
+    let (expected, actual, diff) = (expected.as_bitmap(), actual.as_bitmap(), diff.as_bitmap());
+    let writers = [(&expected, '#'), (&actual, '#'), (&diff, 'X')].map(|(bitmap, glyph)| {
+        let mut writer = Writer::new();
+        let margin = "expected".len().saturating_sub(writer.frame(Frame::ASCIIFrame)._columns(bitmap));
+        writer.style(Style::ASCII1x1(glyph)).margin(0, margin, 0, 0);
+        writer
+    });
+    let panels = [
+        Panel::new(&writers[0], &expected).caption("expected"),
+        Panel::new(&writers[1], &actual).caption("actual"),
+        Panel::new(&writers[2], &diff).caption("diff")
+    ];
+    let mut buffer = _Buffer::new();
+    Layout::new(&panels).spacing(4, 1).write(&mut buffer);
+    message.push_str(&buffer.into_string());
+    panic!("{}", message);
+}
+
+/// Pixel of a bitmap, unset outside of it.
+fn _get(bitmap: &BitmapBuf, x: usize, y: usize) -> bool {
+    return x < bitmap.width() && y < bitmap.height() && bitmap.pixel(x, y);
+}
@@ -1,28 +1,61 @@
-use crate::{Frame, Style};
+use crate::{Align, Frame, Rotation, Scale, Style};
 use crate::bitmap::Bitmap;
+use crate::source::Image;
+use crate::diff::Compare;
+use crate::sink::{Sink, _write_to};
 
-#[cfg(feature = "std")]
-use std::io::Write;
-#[cfg(not(feature = "std"))]
-use core::fmt::Write;
+pub(crate) use core::fmt::Write;
+
+pub(crate) type WriteResult = core::fmt::Result;
 
-struct AnsiPosition {
+#[derive(Clone, Copy)]
+pub(crate) struct AnsiPosition {
     line: usize,
     column: usize
 }
 
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Sides {
+    pub(crate) top: usize,
+    pub(crate) right: usize,
+    pub(crate) bottom: usize,
+    pub(crate) left: usize
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct Viewport {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize
+}
+
 /// Write a `Bitmap` conversion either to a buffer or terminal.
-pub struct Writer {
-    _style: Style,
-    _frame: Frame,
-    _ansi_position: Option<AnsiPosition>,
-    _ansi_position_restore: bool,
-    _use_be: bool,
-    _byte_aligned: bool
+#[derive(Clone)]
+pub struct Writer<'a> {
+    pub(crate) _style: Style,
+    pub(crate) _frame: Frame,
+    pub(crate) _title: Option<(&'a str, Align)>,
+    pub(crate) _footer: Option<(&'a str, Align)>,
+    pub(crate) _ansi_position: Option<AnsiPosition>,
+    pub(crate) _ansi_position_restore: bool,
+    pub(crate) _viewport: Option<Viewport>,
+    pub(crate) _scale: Scale,
+    pub(crate) _rotation: Rotation,
+    pub(crate) _flip_horizontal: bool,
+    pub(crate) _flip_vertical: bool,
+    pub(crate) _transpose: bool,
+    pub(crate) _rulers: bool,
+    pub(crate) _padding: Sides,
+    pub(crate) _padding_fill: Option<char>,
+    pub(crate) _margin: Sides,
+    pub(crate) _use_be: bool,
+    pub(crate) _byte_aligned: bool,
+    pub(crate) _compare: Option<Compare<'a>>
 }
 
 /// ```no_std``` compatible unless otherwise noted.
-impl Writer {
+impl<'a> Writer<'a> {
     /// Construct a writer to write or print bitmaps later with defaults settings.
     /// 
     /// The resulting writer instance can be chained for further configurations.
@@ -36,14 +69,27 @@ impl Writer {
     ///    
     /// # Returns
     /// - Writer instance
-    pub fn new() -> Writer {
+    pub fn new() -> Writer<'a> {
         return Writer {
             _style: Style::UnicodeBlock1x2,
             _frame: Frame::NoFrame,
+            _title: None,
+            _footer: None,
             _ansi_position: None,
             _ansi_position_restore: false,
+            _viewport: None,
+            _scale: Scale::Original,
+            _rotation: Rotation::Rotate0,
+            _flip_horizontal: false,
+            _flip_vertical: false,
+            _transpose: false,
+            _rulers: false,
+            _padding: Sides::default(),
+            _padding_fill: None,
+            _margin: Sides::default(),
             _use_be: false,
-            _byte_aligned: false
+            _byte_aligned: false,
+            _compare: None
         }
     }
     
@@ -78,6 +124,255 @@ impl Writer {
         return self;
     }
 
+    /// Set a title that is written into the top edge of the frame.
+    ///
+    /// Titles longer than the frame is wide are cut off. Without a frame,
+    /// the title is not written.
+    ///
+    /// The title is padded with a space on both sides and keeps at least one
+    /// edge character towards the corners, so edges shorter than 5
+    /// characters have no room for it.
+    ///
+    /// ```
+    /// use bitmap_writer::{Align, Bitmap, Frame, Style, Writer};
+    ///
+    /// let bitmap = Bitmap::new(10, 1, &[0b10000000, 0b01000000]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame);
+    ///
+    /// w.title("ab", Align::Left).footer("clock", Align::Left);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), ".- ab -----.\n|#        #|\n'- clock --'\n");
+    /// w.title("ab", Align::Center).footer("clock", Align::Center);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), ".--- ab ---.\n|#        #|\n'- clock --'\n");
+    /// w.title("ab", Align::Right).footer("clock", Align::Right);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), ".----- ab -.\n|#        #|\n'-- clock -'\n");
+    ///
+    /// w.title("a long title", Align::Left).clear_footer();
+    /// assert_eq!(format!("{}", w.display(&bitmap)), ".- a long -.\n|#        #|\n'----------'\n");
+    ///
+    /// let narrow = Bitmap::new(5, 1, &[0b10001000]);
+    /// assert_eq!(format!("{}", w.display(&narrow)), ".- a -.\n|#   #|\n'-----'\n");
+    /// let narrower = Bitmap::new(4, 1, &[0b10010000]);
+    /// assert_eq!(format!("{}", w.display(&narrower)), ".----.\n|#  #|\n'----'\n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `title`: The title text.
+    /// - `align`: Position of the title within the edge of the frame.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn title(&mut self, title: &'a str, align: Align) -> &mut Self {
+        self._title = Some((title, align));
+        return self;
+    }
+
+    /// Removes the title previously set by `title(..)`.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn clear_title(&mut self) -> &mut Self {
+        self._title = None;
+        return self;
+    }
+
+    /// Set a footer that is written into the bottom edge of the frame.
+    ///
+    /// Footers longer than the frame is wide are cut off. Without a frame,
+    /// the footer is not written.
+    ///
+    /// # Arguments
+    /// - `footer`: The footer text.
+    /// - `align`: Position of the footer within the edge of the frame.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn footer(&mut self, footer: &'a str, align: Align) -> &mut Self {
+        self._footer = Some((footer, align));
+        return self;
+    }
+
+    /// Removes the footer previously set by `footer(..)`.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn clear_footer(&mut self) -> &mut Self {
+        self._footer = None;
+        return self;
+    }
+
+    /// Whether to write rulers above and left of the bitmap, outside of the
+    /// frame.
+    ///
+    /// The rulers have a tick mark every 8 pixels, labeled with the pixel's
+    /// coordinate. The coordinates count pixels of the rotated bitmap before
+    /// scaling, and take the viewport into account.
+    ///
+    /// Characters containing more than one pixel carry the tick of the first
+    /// multiple of 8 among them. Labels that would run into the previous
+    /// label are left out, while the tick mark is kept.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Frame, Style, Writer};
+    ///
+    /// let outline = Bitmap::new(10, 10, &[
+    ///     0xff, 0xc0, 0x80, 0x40, 0x80, 0x40, 0x80, 0x40, 0x80, 0x40,
+    ///     0x80, 0x40, 0x80, 0x40, 0x80, 0x40, 0x80, 0x40, 0xff, 0xc0
+    /// ]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame).byte_aligned(true).rulers(true);
+    /// let text = format!("{}", w.display(&outline));
+    /// assert_eq!(text.lines().collect::<Vec<_>>(), [
+    ///     "   0       8  ",
+    ///     "   |       |  ",
+    ///     "  .----------.",
+    ///     "0-|##########|",
+    ///     "  |#        #|",
+    ///     "  |#        #|",
+    ///     "  |#        #|",
+    ///     "  |#        #|",
+    ///     "  |#        #|",
+    ///     "  |#        #|",
+    ///     "  |#        #|",
+    ///     "8-|#        #|",
+    ///     "  |##########|",
+    ///     "  '----------'"
+    /// ]);
+    ///
+    /// // Ticks every 4 characters, too close for labels of 4 digits.
+    /// let wide = Bitmap::new(1040, 3, &[0; 390]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::UnicodeSextant2x3).rulers(true).viewport(1000, 0, 40, 3);
+    /// let text = format!("{}", w.display(&wide));
+    /// assert_eq!(text.lines().take(2).collect::<Vec<_>>(), [
+    ///     "  1000    1016    1032",
+    ///     "  |   |   |   |   |   "
+    /// ]);
+    /// ```
+    ///
+    /// # Arguments
+    /// - `state`: Set to `true` to write rulers.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn rulers(&mut self, state: bool) -> &mut Self {
+        self._rulers = state;
+        return self;
+    }
+
+    /// Set the space between the bitmap and the frame, in characters.
+    ///
+    /// The padding is filled with the center character of the frame, which is
+    /// a blank space for all but `Frame::Custom` and `Frame::Spec` frames,
+    /// unless another character is set by `padding_fill(..)`.
+    ///
+    /// Without a frame, the padding is still written around the bitmap.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Frame, Style, Writer};
+    ///
+    /// let icon = Bitmap::new(4, 2, &[0b10010110]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame).padding(1, 2, 0, 1);
+    /// let text = format!("{}", w.display(&icon));
+    /// assert_eq!(text.lines().collect::<Vec<_>>(), [
+    ///     ".-------.",
+    ///     "|       |",
+    ///     "| #  #  |",
+    ///     "|  ##   |",
+    ///     "'-------'"
+    /// ]);
+    ///
+    /// w.frame(Frame::NoFrame).padding(1, 1, 1, 1);
+    /// assert_eq!(format!("{}", w.display(&icon)), "      \n #  # \n  ##  \n      \n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `top`: Number of lines above the bitmap.
+    /// - `right`: Number of characters right of the bitmap.
+    /// - `bottom`: Number of lines below the bitmap.
+    /// - `left`: Number of characters left of the bitmap.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn padding(&mut self, top: usize, right: usize, bottom: usize, left: usize) -> &mut Self {
+        self._padding = Sides { top, right, bottom, left };
+        return self;
+    }
+
+    /// Set the character used to fill the padding set by `padding(..)`.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Frame, Style, Writer};
+    ///
+    /// let icon = Bitmap::new(4, 2, &[0b10010110]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame).padding(1, 2, 0, 1).padding_fill('.');
+    /// let text = format!("{}", w.display(&icon));
+    /// assert_eq!(text.lines().collect::<Vec<_>>(), [
+    ///     ".-------.",
+    ///     "|.......|",
+    ///     "|.#  #..|",
+    ///     "|. ## ..|",
+    ///     "'-------'"
+    /// ]);
+    /// ```
+    ///
+    /// # Arguments
+    /// - `fill`: The fill character.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn padding_fill(&mut self, fill: char) -> &mut Self {
+        self._padding_fill = Some(fill);
+        return self;
+    }
+
+    /// Set the blank space around everything that is written, including the
+    /// frame and rulers, in characters.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Frame, Style, Writer};
+    ///
+    /// let icon = Bitmap::new(4, 2, &[0b10010110]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).frame(Frame::ASCIIFrame).padding(0, 1, 0, 1).rulers(true).margin(1, 0, 0, 2);
+    /// let text = format!("{}", w.display(&icon));
+    /// assert_eq!(text.lines().collect::<Vec<_>>(), [
+    ///     "            ",
+    ///     "      0     ",
+    ///     "      |     ",
+    ///     "    .------.",
+    ///     "  0-| #  # |",
+    ///     "    |  ##  |",
+    ///     "    '------'"
+    /// ]);
+    ///
+    /// w.rulers(false).margin(0, 1, 2, 0);
+    /// let text = format!("{}", w.display(&icon));
+    /// assert_eq!(text.lines().collect::<Vec<_>>(), [
+    ///     ".------. ",
+    ///     "| #  # | ",
+    ///     "|  ##  | ",
+    ///     "'------' ",
+    ///     "         ",
+    ///     "         "
+    /// ]);
+    /// ```
+    ///
+    /// # Arguments
+    /// - `top`: Number of empty lines above.
+    /// - `right`: Number of blank characters on the right.
+    /// - `bottom`: Number of empty lines below.
+    /// - `left`: Number of blank characters on the left.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn margin(&mut self, top: usize, right: usize, bottom: usize, left: usize) -> &mut Self {
+        self._margin = Sides { top, right, bottom, left };
+        return self;
+    }
+
     /// Set a specific line and column the bitmap will be written to.
     /// 
     /// It uses ANSI commands which might not be supported by all terminals
@@ -93,7 +388,7 @@ impl Writer {
     /// # Returns
     /// - Reference to writer.
     pub fn ansi_position(&mut self, line: usize, column: usize) -> &mut Self {
-        self._ansi_position = Some(AnsiPosition { line: line, column: column });
+        self._ansi_position = Some(AnsiPosition { line, column });
         return self;
     }
 
@@ -126,6 +421,183 @@ impl Writer {
         return self;
     }
 
+    /// Restrict the output to a rectangular region of the bitmap.
+    ///
+    /// Only the pixels inside the region are written, which allows to show
+    /// parts of a bitmap that is larger than the terminal. The region is
+    /// clipped to the bitmap's size.
+    ///
+    /// The region is given in pixels of the scaled bitmap, see `scale(..)`.
+    ///
+    /// # Arguments
+    /// - `x`: Left-most pixel column of the region.
+    /// - `y`: Top-most pixel row of the region.
+    /// - `width`: Pixel width of the region.
+    /// - `height`: Pixel height of the region.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn viewport(&mut self, x: usize, y: usize, width: usize, height: usize) -> &mut Self {
+        self._viewport = Some(Viewport { x, y, width, height });
+        return self;
+    }
+
+    /// Removes the region previously set by `viewport(..)`, writing the whole
+    /// bitmap again.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn clear_viewport(&mut self) -> &mut Self {
+        self._viewport = None;
+        return self;
+    }
+
+    /// Scale the bitmap up or down before writing it.
+    ///
+    /// Scaling up repeats every pixel, making tiny bitmaps readable with the
+    /// space-efficient styles. Scaling down combines blocks of pixels, making
+    /// huge bitmaps fit onto the screen.
+    ///
+    /// The bitmap itself is not modified, scaling happens while writing.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Scale, Style, Writer};
+    ///
+    /// let bitmap = Bitmap::new(5, 3, &[0b11011000, 0b10010000, 0b01001000]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).byte_aligned(true);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "## ##\n#  # \n #  #\n");
+    ///
+    /// w.scale(Scale::Up(2));
+    /// let text = format!("{}", w.display(&bitmap));
+    /// assert_eq!(text, "####  ####\n####  ####\n##    ##  \n##    ##  \n  ##    ##\n  ##    ##\n");
+    ///
+    /// // Blocks at the right and bottom edge are partial, here 1 pixel wide or high.
+    /// w.scale(Scale::DownNearest(2));
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "# #\n  #\n");
+    /// w.scale(Scale::DownOr(2));
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "###\n# #\n");
+    /// // More than half of the pixels of a block must be set, a tie is unset.
+    /// w.scale(Scale::DownMajority(2));
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "#  \n  #\n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `scale`: See `bitmap_writer::Scale`
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn scale(&mut self, scale: Scale) -> &mut Self {
+        self._scale = scale;
+        return self;
+    }
+
+    /// Rotate the bitmap clockwise before writing it.
+    ///
+    /// Useful for previewing framebuffers of displays that are mounted
+    /// rotated.
+    ///
+    /// Flipping and transposing is applied first, then the rotation, and
+    /// finally the scaling. The bitmap itself is not modified.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Rotation, Style, Writer};
+    ///
+    /// let bitmap = Bitmap::new(3, 2, &[0b11100000, 0b10000000]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).byte_aligned(true);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "###\n#  \n");
+    ///
+    /// w.rotate(Rotation::Rotate90);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "##\n #\n #\n");
+    /// w.rotate(Rotation::Rotate180);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "  #\n###\n");
+    /// w.rotate(Rotation::Rotate270);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "# \n# \n##\n");
+    ///
+    /// w.rotate(Rotation::Rotate90).flip_horizontal(true);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), " #\n #\n##\n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `rotation`: See `bitmap_writer::Rotation`
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn rotate(&mut self, rotation: Rotation) -> &mut Self {
+        self._rotation = rotation;
+        return self;
+    }
+
+    /// Whether to mirror the bitmap left to right before writing it.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Style, Writer};
+    ///
+    /// let bitmap = Bitmap::new(3, 2, &[0b11100000, 0b10000000]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).byte_aligned(true).flip_horizontal(true);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "###\n  #\n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `state`: Set to `true` to flip horizontally.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn flip_horizontal(&mut self, state: bool) -> &mut Self {
+        self._flip_horizontal = state;
+        return self;
+    }
+
+    /// Whether to mirror the bitmap top to bottom before writing it.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Style, Writer};
+    ///
+    /// let bitmap = Bitmap::new(3, 2, &[0b11100000, 0b10000000]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).byte_aligned(true).flip_vertical(true);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "#  \n###\n");
+    ///
+    /// w.flip_horizontal(true);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "  #\n###\n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `state`: Set to `true` to flip vertically.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn flip_vertical(&mut self, state: bool) -> &mut Self {
+        self._flip_vertical = state;
+        return self;
+    }
+
+    /// Whether to swap the rows and columns of the bitmap before writing it,
+    /// mirroring it along the diagonal from the top-left to the bottom-right.
+    ///
+    /// Useful for framebuffers that store pixels column by column.
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Style, Writer};
+    ///
+    /// let bitmap = Bitmap::new(3, 2, &[0b11100000, 0b10000000]);
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#')).byte_aligned(true).transpose(true);
+    /// assert_eq!(format!("{}", w.display(&bitmap)), "##\n# \n# \n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `state`: Set to `true` to transpose.
+    ///
+    /// # Returns
+    /// - Reference to writer.
+    pub fn transpose(&mut self, state: bool) -> &mut Self {
+        self._transpose = state;
+        return self;
+    }
+
     /// Whether to use big-endian or little-endian encoding when decoding
     /// the bytes of the bitmap.
     /// 
@@ -145,7 +617,8 @@ impl Writer {
     /// or textures in VRAM mostly use big-endian.
     /// 
     /// Choosing the wrong endianess will result in 8-pixel wide mirrored
-    /// columns in the writer output.
+    /// columns in the writer output. If instead the whole bitmap is mirrored,
+    /// use `flip_horizontal(..)`.
     /// 
     /// # Arguments
     /// - `state`: Set to `true` to use big-endian byte encoding.
@@ -167,7 +640,26 @@ impl Writer {
     /// When set to `false` the bitmap is expected to be non-byte-aligned. The first
     /// pixel of the next line uses the first remaining pixel of the last byte of
     /// the last line.
-    ///     
+    ///
+    /// ```
+    /// use bitmap_writer::{Bitmap, Style, Writer};
+    ///
+    /// let mut w = Writer::new();
+    /// w.style(Style::ASCII1x1('#'));
+    ///
+    /// // Rows of 5 pixels, continuing in the middle of a byte.
+    /// let unaligned = Bitmap::new(5, 3, &[0b10001010, 0b10001000]);
+    /// assert_eq!(format!("{}", w.display(&unaligned)), "#   #\n # # \n  #  \n");
+    ///
+    /// let unaligned_be = Bitmap::new(5, 3, &[0b01010001, 0b00010001]);
+    /// w.be(true);
+    /// assert_eq!(format!("{}", w.display(&unaligned_be)), "#   #\n # # \n  #  \n");
+    /// w.be(false);
+    ///
+    /// let aligned = Bitmap::new(5, 3, &[0b10001000, 0b01010000, 0b00100000]);
+    /// w.byte_aligned(true);
+    /// assert_eq!(format!("{}", w.display(&aligned)), "#   #\n # # \n  #  \n");
+    /// ```
     /// 
     /// # Arguments
     /// - `state`: Sets the byte-alignment of the bitmap.
@@ -179,155 +671,647 @@ impl Writer {
         return self;
     }
 
+    /// Number of bytes a bitmap of the given size takes up with this writer's
+    /// byte-alignment, for example to know how much of a file to read or how
+    /// far apart consecutive images of a sprite sheet are.
+    ///
+    /// ```
+    /// let mut w = bitmap_writer::Writer::new();
+    /// assert_eq!(w.data_len(10, 3), 4);
+    /// assert_eq!(w.byte_aligned(true).data_len(10, 3), 6);
+    /// ```
+    ///
+    /// # Arguments
+    /// - `width`: Pixel width.
+    /// - `height`: Pixel height.
+    ///
+    /// # Returns
+    /// - Length of the pixel data in bytes.
+    pub fn data_len(&self, width: usize, height: usize) -> usize {
+        if self._byte_aligned { return width.div_ceil(8) * height; }
+        return (width * height).div_ceil(8);
+    }
+
     /// After setting up the writer, finally convert the bitmap into a string of
     /// characters that can be printed out to terminal or transfered to a display
     /// device.
     /// 
     /// # Arguments
-    /// - `writer`: A writer implementing the `Write` trait. It will contain the result of the bitmap conversion.
-    /// - `bitmap`: The bitmap to write into the writer. It contains both size and pixel contents.    
-    pub fn write(&self, writer: &mut impl Write, bitmap: &Bitmap){                    
-        self._write_bitmap(writer, bitmap._pixels, bitmap._w, bitmap._h, &self._style, &[], &self._ansi_position, self._ansi_position_restore, &self._frame);        
-    }
-
-    fn _write_bitmap(&self, w: &mut impl Write, bitmap: &[u8], width: usize, height: usize, style_ref: &Style, blocks: &[char], ansi_position: &Option<AnsiPosition>, ansi_position_reset: bool, frame_ref: &Frame){    
-        let mut line: usize = 0;
-        
-        let nine_patch: [char;9];
-        match frame_ref {        
-            Frame::ASCIIFrame =>  { nine_patch = ['.', '-', '.', '|', ' ', '|', '\'', '-', '\'']; }
-            Frame::UnicodeBoldFrame =>   { nine_patch = ['┏', '━', '┓', '┃', ' ', '┃', '┗', '━', '┛']; }
-            Frame::UnicodeDoubleUFrame => { nine_patch = ['╔', '═', '╗', '║', ' ', '║', '╚', '═', '╝']; }
-            Frame::UnicodeBlockFrame =>  { nine_patch = ['▞', '▀', '▚', '▌', ' ', '▐', '▚', '▄', '▞']; }
-            Frame::UnicodeShadeFrame =>  { nine_patch = ['🮞', '🮐', '🮟', '🮐', ' ', '🮐', '🮝', '🮐', '🮜']; }
-            _ =>                  { nine_patch = ['┌', '─', '┐', '│', ' ', '│', '└', '─', '┘']; }
-        }             
-        
-        if ansi_position_reset {
-            let Ok(_v) = self._ansi_store_position(w) else { return };
-        }else
-        if ansi_position.is_some() {
-            let Ok(_v) = self._ansi_set_position(w, ansi_position.as_ref().unwrap(), line) else { return };  
-        }      
-        
-        struct StyleSettings <'a>{
-            blocks: &'a[char],
-            w: usize,
-            h: usize,
+    /// - `writer`: A sink implementing `core::fmt::Write` or `std::io::Write`, see `bitmap_writer::Sink`. It will contain the result of the bitmap conversion.
+    /// - `bitmap`: The bitmap to write into the writer. It contains both size and pixel contents. Any `PixelSource` can be written as well, see `bitmap_writer::Image`.
+    pub fn write<K>(&self, writer: &mut impl Sink<K>, bitmap: &(impl Image + ?Sized)){
+        _write_to(writer, |w| self._write_bitmap(w, bitmap));
+    }
+
+    pub(crate) fn _write_bitmap(&self, w: &mut impl Write, bitmap: &(impl Image + ?Sized)) -> WriteResult {
+        for part in 0..self._parts(bitmap) {
+            self._write_part(w, bitmap, part)?;
         }
-        let style: StyleSettings = match style_ref {            
-            Style::ASCII1x1(char) => { StyleSettings { w: 1, h: 1, blocks: &[' ', *char] } }
-            Style::UnicodeBlock1x1 => { StyleSettings { w: 1, h: 1, blocks: &[' ', '█'] } }
-            Style::UnicodeBlock1x2 => { StyleSettings { w: 1, h: 2, blocks: &[' ', '▀', '▄', '█'] } }
-            Style::UnicodeBlock2x2 => { StyleSettings { w: 2, h: 2, blocks: &[
-                ' ',  '▘', '▝', '▀', '▖', '▋', '▞', '▛',
-                '▗', '▚', '▐' , '▜', '▄', '▙', '▟', '█'
-            ] } }
-            Style::UnicodeSextant1x3 => { StyleSettings { w: 1, h: 3, blocks: &[' ',  '🬀', '🬃', '🬄', '🬏', '🬐', '🬓', '▋' ] } }
-            Style::UnicodeSextant2x3 => { StyleSettings { w: 2, h: 3, blocks: &[
-                ' ',  '🬀', '🬁', '🬂', '🬃', '🬄', '🬅', '🬆',
-                '🬇', '🬈', '🬉', '🬊', '🬋', '🬌', '🬍', '🬎',
-                '🬏', '🬐', '🬑', '🬒', '🬓', '▋', '🬔', '🬕',
-                '🬖', '🬗', '🬘', '🬙', '🬚', '🬛', '🬜', '🬝',
-                '🬞', '🬟', '🬠', '🬡', '🬢', '🬣', '🬤', '🬥',
-                '🬦', '🬧', '▐', '🬨', '🬩', '🬪', '🬫', '🬬',
-                '🬭', '🬮', '🬯', '🬰', '🬱', '🬲', '🬳', '🬴',
-                '🬵', '🬶', '🬷', '🬸', '🬹', '🬺', '🬻', '🮋'
-            ] } }
-        };
+        return Ok(());
+    }
 
-        match frame_ref {
-            Frame::NoFrame => { }
-            _ => {
-                let Ok(_v) = write!(w, "{}", nine_patch[0]) else { return };
-                for _i in 0..((width + style.w - 1) / style.w) { let Ok(_v) = write!(w, "{}", nine_patch[1]) else { return } ; }
-                let Ok(_v) = writeln!(w, "{}", nine_patch[2]) else { return };
-                
-                line += 1;
-            }
+    /// Number of parts the output is written in, see `_write_part(..)`.
+    pub(crate) fn _parts(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        return self._lines(bitmap) + 2;
+    }
+
+    /// Write a part of the output: first the ANSI command storing the
+    /// cursor position, then every text line with its ANSI position command
+    /// and line break, and last the ANSI command restoring the position.
+    pub(crate) fn _write_part(&self, w: &mut impl Write, bitmap: &(impl Image + ?Sized), part: usize) -> WriteResult {
+        let lines = self._lines(bitmap);
+        if part == 0 || part > lines {
+            if !self._ansi_position_restore { return Ok(()); }
+            return if part == 0 { self._ansi_store_position(w) } else { self._ansi_restore_position(w) };
+        }
+
+        let line = part - 1;
+        if !self._ansi_position_restore && let Some(pos) = &self._ansi_position {
+            self._ansi_set_position(w, pos, line)?;
+        }
+        self._write_line(w, bitmap, line)?;
+        return writeln!(w);
+    }
+
+    /// Number of text lines the bitmap is written as, including the margin,
+    /// rulers, frame and padding.
+    pub(crate) fn _lines(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        return self._margin.top + self._ruled_lines(bitmap) + self._margin.bottom;
+    }
+
+    /// Number of characters per text line, including the margin, rulers,
+    /// frame and padding.
+    pub(crate) fn _columns(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        return self._margin.left + self._ruled_columns(bitmap) + self._margin.right;
+    }
+
+    /// Number of characters and text lines around the bitmap, taken up by
+    /// the margin, rulers, frame and padding.
+    pub(crate) fn _decoration_size(&self, bitmap: &(impl Image + ?Sized)) -> (usize, usize) {
+        let (cell_w, cell_h) = self._style._cell_size();
+        let (_x, _y, width, height) = self._region(bitmap);
+        return (self._columns(bitmap) - width.div_ceil(cell_w), self._lines(bitmap) - height.div_ceil(cell_h));
+    }
+
+    /// Write a single text line of the bitmap, without a line break and
+    /// without ANSI commands, apart from the colors of a `Diff`.
+    pub(crate) fn _write_line(&self, w: &mut impl Write, bitmap: &(impl Image + ?Sized), line: usize) -> WriteResult {
+        if line < self._margin.top || line >= self._margin.top + self._ruled_lines(bitmap) {
+            return self._write_spaces(w, self._columns(bitmap));
         }
-        
-        for y in (0..height).step_by(style.h) {
-            
-            if ansi_position_reset == false && ansi_position.is_some() {
-               let Ok(_v) = self._ansi_set_position(w, ansi_position.as_ref().unwrap(), line) else { return };
+        self._write_spaces(w, self._margin.left)?;
+        self._write_ruled_line(w, bitmap, line - self._margin.top)?;
+        return self._write_spaces(w, self._margin.right);
+    }
+
+    fn _ruled_lines(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        return self._framed_lines(bitmap) + if self._rulers { 2 } else { 0 };
+    }
+
+    fn _ruled_columns(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        return self._framed_columns(bitmap) + self._ruler_width(bitmap);
+    }
+
+    fn _write_ruled_line(&self, w: &mut impl Write, bitmap: &(impl Image + ?Sized), line: usize) -> WriteResult {
+        if !self._rulers {
+            return self._write_framed_line(w, bitmap, line);
+        }
+
+        let (cell_w, cell_h) = self._style._cell_size();
+        let (left, top, width, height) = self._region(bitmap);
+        let ruler_width = self._ruler_width(bitmap);
+        let framed = if self._frame == Frame::NoFrame { 0 } else { 1 };
+        let offset = ruler_width + framed + self._padding.left;
+        let columns = self._ruled_columns(bitmap);
+
+        if line == 0 {
+            let mut column = 0;
+            for cell in 0..width.div_ceil(cell_w) {
+                let gap = if column > 0 { 1 } else { 0 };
+                if let Some(label) = self._ruler_tick(left, cell, cell_w) && offset + cell >= column + gap && offset + cell + _digits(label) <= columns {
+                    self._write_spaces(w, offset + cell - column)?;
+                    write!(w, "{}", label)?;
+                    column = offset + cell + _digits(label);
+                }
             }
-            
-            match frame_ref {
-                Frame::NoFrame => { }
-                _ => { let Ok(_v) = write!(w, "{}", nine_patch[3]) else { return }; }            
+            return self._write_spaces(w, columns - column);
+        }
+
+        if line == 1 {
+            self._write_spaces(w, offset)?;
+            for cell in 0..width.div_ceil(cell_w) {
+                write!(w, "{}", if self._ruler_tick(left, cell, cell_w).is_some() { '|' } else { ' ' })?;
             }
-            
-            for x in (0..width).step_by(style.w) {
-                let mut block: usize = 0;
-                for yy in 0..style.h {
-                    for xx in 0..style.w {
-                        let w = if self._byte_aligned { (width + 7) / 8 * 8 } else { width };
-                        let byte: usize = ((y + yy) * w + x + xx) / 8;
-                        let mask: u8 = 1 << ((if self._use_be { (x + xx) % 8 } else { 7 - (x + xx) % 8 } ));
-                        if byte < bitmap.len() && bitmap[byte] & mask != 0 { block |= 1 << (yy * style.w + xx); }                                
-                    }
-                }                                                     
-               let Ok(_v) = write!(w, "{}", if block < blocks.len() { blocks[block] } else if block < style.blocks.len() { style.blocks[block] } else { ' ' }) else { return };                       
+            return self._write_spaces(w, columns - offset - width.div_ceil(cell_w));
+        }
+
+        let line = line - 2;
+        let row = line.checked_sub(framed + self._padding.top).filter(|row| *row < height.div_ceil(cell_h));
+        match row.and_then(|row| self._ruler_tick(top, row, cell_h)) {
+            Some(label) => {
+                self._write_spaces(w, ruler_width - 1 - _digits(label))?;
+                write!(w, "{}-", label)?;
             }
-            
-            match frame_ref {
-                Frame::NoFrame => { if y < height { let Ok(_v) = writeln!(w, "") else { return }; } }
-                _ => { let Ok(_v) = writeln!(w, "{}", nine_patch[5]) else { return }; }
+            None => { self._write_spaces(w, ruler_width)?; }
+        }
+        return self._write_framed_line(w, bitmap, line);
+    }
+
+    fn _framed_lines(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        return self._padded_lines(bitmap) + if self._frame == Frame::NoFrame { 0 } else { 2 };
+    }
+
+    fn _framed_columns(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        return self._padded_columns(bitmap) + if self._frame == Frame::NoFrame { 0 } else { 2 };
+    }
+
+    fn _write_framed_line(&self, w: &mut impl Write, bitmap: &(impl Image + ?Sized), line: usize) -> WriteResult {
+        if self._frame == Frame::NoFrame {
+            return self._write_padded_line(w, bitmap, line);
+        }
+
+        if line == 0 || line == self._padded_lines(bitmap) + 1 {
+            let (edge, label) = if line == 0 { (0, self._title) } else { (6, self._footer) };
+            write!(w, "{}", self._frame._nine_patch(edge, 0))?;
+            self._write_edge(w, edge + 1, self._padded_columns(bitmap), label)?;
+            return write!(w, "{}", self._frame._nine_patch(edge + 2, 0));
+        }
+
+        write!(w, "{}", self._frame._nine_patch(3, line - 1))?;
+        self._write_padded_line(w, bitmap, line - 1)?;
+        return write!(w, "{}", self._frame._nine_patch(5, line - 1));
+    }
+
+    fn _padded_lines(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        let (_cell_w, cell_h) = self._style._cell_size();
+        let (_x, _y, _width, height) = self._region(bitmap);
+        return self._padding.top + height.div_ceil(cell_h) + self._padding.bottom;
+    }
+
+    fn _padded_columns(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        let (cell_w, _cell_h) = self._style._cell_size();
+        let (_x, _y, width, _height) = self._region(bitmap);
+        return self._padding.left + width.div_ceil(cell_w) + self._padding.right;
+    }
+
+    fn _write_padded_line(&self, w: &mut impl Write, bitmap: &(impl Image + ?Sized), line: usize) -> WriteResult {
+        let (cell_w, cell_h) = self._style._cell_size();
+        let (left, top, width, height) = self._region(bitmap);
+        let fill = self._padding_fill.unwrap_or(self._frame._nine_patch(4, 0));
+
+        if line < self._padding.top || line >= self._padding.top + height.div_ceil(cell_h) {
+            for _i in 0..self._padded_columns(bitmap) { write!(w, "{}", fill)?; }
+            return Ok(());
+        }
+
+        for _i in 0..self._padding.left { write!(w, "{}", fill)?; }
+
+        let region = (left, top, width, height);
+        let y = (line - self._padding.top) * cell_h;
+        for x in (0..width).step_by(cell_w) {
+            let block = self._cell_block(bitmap, region, x, y);
+            match &self._compare {
+                Some(compare) => { compare._write_cell(w, self._style, block, self._cell_block(compare._bitmap, region, x, y))?; }
+                None => { write!(w, "{}", self._style._glyph(block))?; }
             }
+        }
 
-            line += 1;
-        }   
+        for _i in 0..self._padding.right { write!(w, "{}", fill)?; }
+        return Ok(());
+    }
 
-        if ansi_position_reset == false && ansi_position.is_some() {
-            let Ok(_v) = self._ansi_set_position(w, ansi_position.as_ref().unwrap(), line) else { return };
+    /// Pixels of the character cell starting at `x`, `y` within the region,
+    /// as the block index used by `Style::_glyph(..)`.
+    fn _cell_block(&self, bitmap: &(impl Image + ?Sized), region: (usize, usize, usize, usize), x: usize, y: usize) -> usize {
+        let (cell_w, cell_h) = self._style._cell_size();
+        let (left, top, width, height) = region;
+        let mut block: usize = 0;
+        for yy in 0..cell_h {
+            for xx in 0..cell_w {
+                if x + xx < width && y + yy < height && self._sample(bitmap, left + x + xx, top + y + yy) { block |= 1 << (yy * cell_w + xx); }
+            }
         }
+        return block;
+    }
+
+    /// Width of the ruler left of the bitmap, fitting the largest row label
+    /// and the tick mark.
+    fn _ruler_width(&self, bitmap: &(impl Image + ?Sized)) -> usize {
+        if !self._rulers { return 0; }
+        let (_cell_w, cell_h) = self._style._cell_size();
+        let (_x, top, _width, height) = self._region(bitmap);
+        let widest = (0..height.div_ceil(cell_h)).filter_map(|row| self._ruler_tick(top, row, cell_h)).map(_digits).max().unwrap_or(0);
+        return widest + 1;
+    }
+
+    /// The label of the ruler tick within a character cell, if any.
+    ///
+    /// Ticks are placed every 8 pixels of the unscaled bitmap, at the first
+    /// scaled pixel of the tick's pixel.
+    fn _ruler_tick(&self, offset: usize, cell: usize, cell_size: usize) -> Option<usize> {
+        let (up, down) = match self._scale {
+            Scale::Original => (1, 1),
+            Scale::Up(n) => (n.max(1), 1),
+            Scale::DownNearest(n) | Scale::DownOr(n) | Scale::DownMajority(n) => (1, n.max(1))
+        };
+        let start = offset + cell * cell_size;
+        let tick = (start * down).div_ceil(8 * up) * 8;
+        return if tick * up / down < start + cell_size { Some(tick) } else { None };
+    }
+
+    fn _write_spaces(&self, w: &mut impl Write, count: usize) -> WriteResult {
+        for _i in 0..count { write!(w, " ")?; }
+        return Ok(());
+    }
 
-        match frame_ref {
-            Frame::NoFrame => { }
-            _ => {
-                let Ok(_v) = write!(w, "{}", nine_patch[6]) else { return };
-                for _i in 0..((width + style.w - 1) / style.w) { let Ok(_v) =  write!(w, "{}", nine_patch[7]) else { return }; }
-                let Ok(_v) = writeln!(w, "{}", nine_patch[8]) else { return };                
-            }                    
+    /// Write the top or bottom edge of the frame with an optional label.
+    ///
+    /// The label is padded with a space on both sides and keeps at least one
+    /// edge character towards the corners.
+    fn _write_edge(&self, w: &mut impl Write, part: usize, length: usize, label: Option<(&str, Align)>) -> WriteResult {
+        let (text, align) = label.unwrap_or(("", Align::Left));
+        let text_length = text.chars().count().min(length.saturating_sub(4));
+        if text_length == 0 {
+            for i in 0..length { write!(w, "{}", self._frame._nine_patch(part, i))?; }
+            return Ok(());
         }
 
-        if ansi_position_reset {
-           let Ok(_v) = self._ansi_restore_position(w) else { return  };
+        let start = match align {
+            Align::Left => 1,
+            Align::Center => (length - text_length - 2) / 2,
+            Align::Right => length - text_length - 3
+        };
+        for i in 0..start { write!(w, "{}", self._frame._nine_patch(part, i))?; }
+        write!(w, " ")?;
+        for c in text.chars().take(text_length) { write!(w, "{}", c)?; }
+        write!(w, " ")?;
+        for i in start + text_length + 2..length { write!(w, "{}", self._frame._nine_patch(part, i))?; }
+        return Ok(());
+    }
+
+    /// The part of the bitmap to write as `(x, y, width, height)`, taking the
+    /// viewport into account.
+    pub(crate) fn _region(&self, bitmap: &(impl Image + ?Sized)) -> (usize, usize, usize, usize) {
+        let (width, height) = self._size(bitmap);
+        return match &self._viewport {
+            Some(v) => {
+                let x = v.x.min(width);
+                let y = v.y.min(height);
+                (x, y, v.width.min(width - x), v.height.min(height - y))
+            }
+            None => (0, 0, width, height)
+        };
+    }
+
+    /// Pixel size of the bitmap after rotation and scaling. With a bitmap to
+    /// compare, see `Diff`, the size covering both bitmaps.
+    pub(crate) fn _size(&self, bitmap: &(impl Image + ?Sized)) -> (usize, usize) {
+        let (width, height) = self._scaled_size(bitmap);
+        return match &self._compare {
+            Some(compare) => {
+                let (compare_w, compare_h) = self._scaled_size(compare._bitmap);
+                (width.max(compare_w), height.max(compare_h))
+            }
+            None => (width, height)
+        };
+    }
+
+    fn _scaled_size(&self, bitmap: &(impl Image + ?Sized)) -> (usize, usize) {
+        let (width, height) = self._oriented_size(bitmap);
+        return match self._scale {
+            Scale::Original => (width, height),
+            Scale::Up(n) => (width * n.max(1), height * n.max(1)),
+            Scale::DownNearest(n) | Scale::DownOr(n) | Scale::DownMajority(n) => (width.div_ceil(n.max(1)), height.div_ceil(n.max(1)))
+        };
+    }
+
+    /// Pixel size of the bitmap after flipping, transposing and rotation.
+    fn _oriented_size(&self, bitmap: &(impl Image + ?Sized)) -> (usize, usize) {
+        let swap = self._transpose != matches!(self._rotation, Rotation::Rotate90 | Rotation::Rotate270);
+        let (width, height) = bitmap._dimensions();
+        return if swap { (height, width) } else { (width, height) };
+    }
+
+    /// Sample a single pixel of the flipped, transposed and rotated bitmap.
+    fn _oriented(&self, bitmap: &(impl Image + ?Sized), x: usize, y: usize) -> bool {
+        let (oriented_w, oriented_h) = self._oriented_size(bitmap);
+        if x >= oriented_w || y >= oriented_h { return false; }
+        let (bitmap_w, bitmap_h) = bitmap._dimensions();
+        let (width, height) = if self._transpose { (bitmap_h, bitmap_w) } else { (bitmap_w, bitmap_h) };
+        let (x, y) = match self._rotation {
+            Rotation::Rotate0 => (x, y),
+            Rotation::Rotate90 => (y, height - 1 - x),
+            Rotation::Rotate180 => (width - 1 - x, height - 1 - y),
+            Rotation::Rotate270 => (width - 1 - y, x)
+        };
+        let (x, y) = if self._transpose { (y, x) } else { (x, y) };
+        let x = if self._flip_horizontal { bitmap_w - 1 - x } else { x };
+        let y = if self._flip_vertical { bitmap_h - 1 - y } else { y };
+        return bitmap._pixel(self, x, y);
+    }
+
+    /// Sample a single pixel of the rotated and scaled bitmap.
+    pub(crate) fn _sample(&self, bitmap: &(impl Image + ?Sized), x: usize, y: usize) -> bool {
+        let (n, majority) = match self._scale {
+            Scale::Original => { return self._oriented(bitmap, x, y); }
+            Scale::Up(n) => { return self._oriented(bitmap, x / n.max(1), y / n.max(1)); }
+            Scale::DownNearest(n) => { return self._oriented(bitmap, x * n.max(1), y * n.max(1)); }
+            Scale::DownOr(n) => (n.max(1), false),
+            Scale::DownMajority(n) => (n.max(1), true)
+        };
+        let (width, height) = self._oriented_size(bitmap);
+        let (mut set, mut total) = (0, 0);
+        for yy in y * n..((y + 1) * n).min(height) {
+            for xx in x * n..((x + 1) * n).min(width) {
+                if self._oriented(bitmap, xx, yy) {
+                    if !majority { return true; }
+                    set += 1;
+                }
+                total += 1;
+            }
         }
+        return majority && set * 2 > total;
+    }
 
-        #[cfg(not(feature = "std"))]
-        return;
-        #[cfg(feature = "std")]
-        let Ok(_v) = w.flush() else { return; };        
+    /// Decode a single pixel of the bitmap according to the endianess and
+    /// byte-alignment settings. Pixels outside of the bitmap are unset.
+    pub(crate) fn _pixel(&self, bitmap: &Bitmap, x: usize, y: usize) -> bool {
+        if x >= bitmap._w || y >= bitmap._h { return false; }
+        let stride = if self._byte_aligned { bitmap._w.div_ceil(8) * 8 } else { bitmap._w };
+        let bit = y * stride + x;
+        let byte = bit / 8;
+        let mask: u8 = 1 << (if self._use_be { bit % 8 } else { 7 - bit % 8 });
+        return byte < bitmap._pixels.len() && bitmap._pixels[byte] & mask != 0;
     }
 
-    fn _ansi_set_position(&self, w: &mut impl Write, pos: &AnsiPosition, line: usize) -> Result<(), impl core::error::Error> {
+    fn _ansi_set_position(&self, w: &mut impl Write, pos: &AnsiPosition, line: usize) -> WriteResult {
        return write!(w, "\x1b[{};{}H", pos.line + line, pos.column);
     }
 
-    fn _ansi_store_position(&self, w: &mut impl Write) -> Result<(), impl core::error::Error> {
+    fn _ansi_store_position(&self, w: &mut impl Write) -> WriteResult {
         return write!(w, "\x1b[s");
     }
 
-    fn _ansi_restore_position(&self, w: &mut impl Write) -> Result<(), impl core::error::Error> {
+    fn _ansi_restore_position(&self, w: &mut impl Write) -> WriteResult {
         return write!(w, "\x1b[u");
     }
 }
 
+fn _digits(value: usize) -> usize {
+    let mut digits = 1;
+    let mut value = value;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    return digits;
+}
+
+impl Default for Writer<'_> {
+    fn default() -> Self {
+        return Writer::new();
+    }
+}
+
+impl Frame {
+    /// Character of one of the frame's nine parts: the corners, edges and
+    /// center, from left to right and top to bottom.
+    ///
+    /// The index is the position along the edge, which selects the character
+    /// of multi-character edges.
+    pub(crate) fn _nine_patch(&self, part: usize, index: usize) -> char {
+        let nine_patch = match self {
+            Frame::ASCIIFrame =>  { ['.', '-', '.', '|', ' ', '|', '\'', '-', '\''] }
+            Frame::UnicodeBoldFrame =>   { ['┏', '━', '┓', '┃', ' ', '┃', '┗', '━', '┛'] }
+            Frame::UnicodeDoubleUFrame => { ['╔', '═', '╗', '║', ' ', '║', '╚', '═', '╝'] }
+            Frame::UnicodeBlockFrame =>  { ['▞', '▀', '▚', '▌', ' ', '▐', '▚', '▄', '▞'] }
+            Frame::UnicodeShadeFrame =>  { ['🮞', '🮐', '🮟', '🮐', ' ', '🮐', '🮝', '🮐', '🮜'] }
+            Frame::UnicodeRoundFrame =>  { ['╭', '─', '╮', '│', ' ', '│', '╰', '─', '╯'] }
+            Frame::Custom(nine_patch) => { *nine_patch }
+            Frame::Spec(spec) => {
+                let edge = match part {
+                    0 => { return spec.corners[0]; }
+                    2 => { return spec.corners[1]; }
+                    6 => { return spec.corners[2]; }
+                    8 => { return spec.corners[3]; }
+                    1 => spec.top,
+                    3 => spec.left,
+                    5 => spec.right,
+                    7 => spec.bottom,
+                    _ => { return spec.fill; }
+                };
+                let length = edge.chars().count();
+                return if length == 0 { ' ' } else { edge.chars().nth(index % length).unwrap_or(' ') };
+            }
+            _ =>                  { ['┌', '─', '┐', '│', ' ', '│', '└', '─', '┘'] }
+        };
+        return nine_patch[part];
+    }
+}
+
+impl Style {
+    /// Width and height of pixels per character.
+    pub(crate) fn _cell_size(&self) -> (usize, usize) {
+        let (w, h, _blocks) = self._blocks();
+        return (w, h);
+    }
+
+    /// The character for a combination of set pixels, indexed by the pixel
+    /// bits from left to right and top to bottom.
+    pub(crate) fn _glyph(&self, block: usize) -> char {
+        if let Style::ASCII1x1(char) = self {
+            return if block == 1 { *char } else { ' ' };
+        }
+        let (_w, _h, blocks) = self._blocks();
+        return if block < blocks.len() { blocks[block] } else { ' ' };
+    }
+
+    /// The combination of set pixels a character stands for, the inverse of
+    /// `_glyph(..)`.
+    pub(crate) fn _block(&self, glyph: char) -> Option<usize> {
+        let (w, h) = self._cell_size();
+        return (0..1 << (w * h)).find(|block| self._glyph(*block) == glyph);
+    }
+
+    fn _blocks(&self) -> (usize, usize, &'static [char]) {
+        return match self {
+            Style::ASCII1x1(_) => { (1, 1, &[]) }
+            Style::UnicodeBlock1x1 => { (1, 1, &[' ', '█']) }
+            Style::UnicodeBlock1x2 => { (1, 2, &[' ', '▀', '▄', '█']) }
+            Style::UnicodeBlock2x2 => { (2, 2, &[
+                ' ',  '▘', '▝', '▀', '▖', '▋', '▞', '▛',
+                '▗', '▚', '▐' , '▜', '▄', '▙', '▟', '█'
+            ]) }
+            Style::UnicodeSextant1x3 => { (1, 3, &[' ',  '🬀', '🬃', '🬄', '🬏', '🬐', '🬓', '▋' ]) }
+            Style::UnicodeSextant2x3 => { (2, 3, &[
+                ' ',  '🬀', '🬁', '🬂', '🬃', '🬄', '🬅', '🬆',
+                '🬇', '🬈', '🬉', '🬊', '🬋', '🬌', '🬍', '🬎',
+                '🬏', '🬐', '🬑', '🬒', '🬓', '▋', '🬔', '🬕',
+                '🬖', '🬗', '🬘', '🬙', '🬚', '🬛', '🬜', '🬝',
+                '🬞', '🬟', '🬠', '🬡', '🬢', '🬣', '🬤', '🬥',
+                '🬦', '🬧', '▐', '🬨', '🬩', '🬪', '🬫', '🬬',
+                '🬭', '🬮', '🬯', '🬰', '🬱', '🬲', '🬳', '🬴',
+                '🬵', '🬶', '🬷', '🬸', '🬹', '🬺', '🬻', '🮋'
+            ]) }
+        };
+    }
+}
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use crate::bitmap::BitmapBuf;
+
+/// Requires `features=["alloc"]` in ```cargo.toml```.
+#[cfg(feature = "alloc")]
+impl Writer<'_> {
+    /// Convert the bitmap into a string, exactly as `write(..)` would write it.
+    ///
+    /// ```
+    /// let bitmap = bitmap_writer::Bitmap::new(4, 2, &[0b10010110]);
+    /// let text = bitmap_writer::Writer::new()
+    ///     .style(bitmap_writer::Style::ASCII1x1('#'))
+    ///     .to_string(&bitmap);
+    /// assert_eq!(text, "#  #\n ## \n");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to convert. It contains both size and pixel contents.
+    ///
+    /// # Returns
+    /// - The result of the bitmap conversion.
+    pub fn to_string(&self, bitmap: &(impl Image + ?Sized)) -> String {
+        let mut buffer = _Buffer::new();
+        let _ = self._write_bitmap(&mut buffer, bitmap);
+        return buffer.into_string();
+    }
+
+    /// Convert the bitmap into an owned bitmap, exactly as the writer sees it.
+    ///
+    /// Endianess and byte-alignment are decoded, and flipping, rotation,
+    /// scaling and the viewport are applied. The result can be written by a
+    /// writer with default settings.
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to convert.
+    ///
+    /// # Returns
+    /// - BitmapBuf instance.
+    pub fn to_bitmap(&self, bitmap: &(impl Image + ?Sized)) -> BitmapBuf {
+        let (left, top, width, height) = self._region(bitmap);
+        let mut result = BitmapBuf::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                result.set_pixel(x, y, self._sample(bitmap, left + x, top + y));
+            }
+        }
+        return result;
+    }
+
+    /// Convert the bitmap into a grid of characters, one row per text line.
+    ///
+    /// Unlike `to_string(..)`, the grid contains no ANSI commands or line
+    /// breaks, only the characters of the bitmap, frame and decorations.
+    ///
+    /// ```
+    /// let bitmap = bitmap_writer::Bitmap::new(4, 2, &[0b10010110]);
+    /// let cells = bitmap_writer::Writer::new()
+    ///     .style(bitmap_writer::Style::ASCII1x1('#'))
+    ///     .render_cells(&bitmap);
+    /// assert_eq!(cells[1], [' ', '#', '#', ' ']);
+    /// ```
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to convert. It contains both size and pixel contents.
+    ///
+    /// # Returns
+    /// - Rows of characters.
+    pub fn render_cells(&self, bitmap: &(impl Image + ?Sized)) -> Vec<Vec<char>> {
+        return (0..self._lines(bitmap)).map(|line| {
+            let mut buffer = _Buffer::new();
+            let _ = self._write_line(&mut buffer, bitmap, line);
+            buffer.into_string().chars().collect()
+        }).collect();
+    }
+}
+
+/// In-memory sink collecting the text written.
+#[cfg(feature = "alloc")]
+pub(crate) struct _Buffer {
+    _string: String
+}
+
+#[cfg(feature = "alloc")]
+impl _Buffer {
+    pub(crate) fn new() -> _Buffer {
+        return _Buffer { _string: String::new() };
+    }
+
+    pub(crate) fn into_string(self) -> String {
+        return self._string;
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Write for _Buffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self._string.push_str(s);
+        return Ok(());
+    }
+}
+
 #[cfg(feature = "std")]
-use io_streams::StreamWriter;
+use crate::sink::_print_to;
 
 /// Requires `features=["std"]` in ```cargo.toml```.
 #[cfg(feature = "std")]
-impl Writer {
+impl Writer<'_> {
     /// After setting up the writer, print the bitmap out to terminal.
     /// 
-    /// **stdout** or it's non-Linux equivalent is used for output.    
+    /// **stdout** is used for output, see `print_to(..)` for the handling
+    /// of errors, which are ignored.
     /// 
     /// # Argument
     /// - `bitmap`: The bitmap to write into the writer. It contains both size and pixel contents.    
-    pub fn print(&self, image: &Bitmap){                        
-        self._write_bitmap(&mut StreamWriter::stdout().unwrap(), image._pixels, image._w, image._h, &self._style, &[], &self._ansi_position, self._ansi_position_restore, &self._frame);        
+    pub fn print(&self, image: &(impl Image + ?Sized)){                        
+        let _ = self.print_to(&mut std::io::stdout().lock(), image);
+    }
+
+    /// Print the bitmap out to terminal on **stderr**, e.g. to keep stdout
+    /// free for machine-readable output. See `print_to(..)` for the handling
+    /// of errors, which are ignored.
+    ///
+    /// # Arguments
+    /// - `bitmap`: The bitmap to write. It contains both size and pixel contents.
+    pub fn eprint(&self, bitmap: &(impl Image + ?Sized)) {
+        let _ = self.print_to(&mut std::io::stderr().lock(), bitmap);
+    }
+
+    /// Print the bitmap to any stream, e.g. a locked stdout or stderr, a
+    /// file or a socket.
+    ///
+    /// The output is buffered and written in large blocks, then the stream
+    /// is flushed, so unbuffered streams like stderr are not written
+    /// character by character. Pass a locked stream, e.g.
+    /// `std::io::stderr().lock()`, to keep other threads from printing into
+    /// the middle of the bitmap.
+    ///
+    /// A broken pipe, when the reading end was closed early, e.g. by
+    /// `head`, stops the output without an error.
+    ///
+    /// ```no_run
+    /// let bitmap = bitmap_writer::Bitmap::new(8, 2, &[0b00111100, 0b01000010]);
+    /// let w = bitmap_writer::Writer::new();
+    /// w.print_to(&mut std::io::stderr().lock(), &bitmap).expect("cannot write preview");
+    /// ```
+    ///
+    /// # Arguments
+    /// - `stream`: The stream to write to.
+    /// - `bitmap`: The bitmap to write. It contains both size and pixel contents.
+    ///
+    /// # Returns
+    /// - The error of the stream, apart from a broken pipe.
+    pub fn print_to(&self, stream: &mut impl std::io::Write, bitmap: &(impl Image + ?Sized)) -> std::io::Result<()> {
+        return _print_to(stream, |w| self._write_bitmap(w, bitmap));
     }
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+//! Runs the `bitmap_writer` command-line tool, which requires
+//! `features=["cli"]` in ```cargo.toml```.
+
+#![allow(clippy::needless_return)]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Run the tool with the arguments and the input on stdin.
+fn run(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_bitmap_writer"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input).unwrap();
+    return child.wait_with_output().unwrap();
+}
+
+/// Run the tool and get its output, which must succeed.
+fn text(args: &[&str], input: &[u8]) -> String {
+    let output = run(args, input);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    return String::from_utf8(output.stdout).unwrap();
+}
+
+/// Run the tool and get its error message, which must fail with the exit code.
+fn error(args: &[&str], input: &[u8], code: i32) -> String {
+    let output = run(args, input);
+    assert_eq!(output.status.code(), Some(code));
+    return String::from_utf8(output.stderr).unwrap();
+}
+
+#[test]
+fn help() {
+    assert!(text(&["--help"], b"").starts_with("Usage: bitmap_writer"));
+    assert!(text(&["--width", "8", "-h"], b"").starts_with("Usage: bitmap_writer"));
+}
+
+#[test]
+fn numbers() {
+    let icon = "  ####  \n #    # \n";
+    assert_eq!(text(&["--style", "ascii", "--width", "8"], &[0x3c, 0x42]), icon);
+    assert_eq!(text(&["--style=ascii", "--width=0x8"], &[0x3c, 0x42]), icon);
+    assert_eq!(text(&["--style", "ascii", "--width", "8", "--offset", "0x1", "--length", "1"], &[0xff, 0x3c, 0x42]), "  ####  \n");
+    assert_eq!(text(&["--style", "ascii", "--width", "4", "--height", "1"], &[0x3c, 0x42]), "  ##\n");
+}
+
+#[test]
+fn usage_errors() {
+    assert!(error(&["--width"], b"", 2).contains("option --width requires a value"));
+    assert!(error(&["--width", "eight"], b"", 2).contains("invalid number for --width: eight"));
+    assert!(error(&["--threshold", "256"], b"", 2).contains("threshold must be 0 to 255: 256"));
+    assert!(error(&["--colour"], b"", 2).contains("unknown option: --colour"));
+    assert!(error(&["--style", "large"], b"", 2).contains("unknown style: large"));
+    assert!(error(&["--frame", "fancy"], b"", 2).contains("unknown frame: fancy"));
+    assert!(error(&["--output", "gif"], b"", 2).contains("unknown output format: gif"));
+    assert!(error(&["--scan", "--width", "8"], b"", 2).contains("--scan requires --width and --height"));
+    assert!(error(&["--scan", "--width", "8", "--height", "1", "--output", "svg"], b"", 2).contains("--scan only writes text"));
+    assert!(error(&["--bytes", "3c", "icon.bin"], b"", 2).contains("--bytes and FILE cannot be used together"));
+    assert!(error(&["icon.bin", "other.bin"], b"", 2).contains("unexpected argument: other.bin"));
+}
+
+#[test]
+fn runtime_errors() {
+    assert!(error(&[], &[0xff, 0x00], 1).contains("raw data requires --width"));
+    assert!(error(&["--format", "gif"], &[0xff], 1).contains("unknown input format: gif"));
+    assert!(error(&["--format", "hex"], &[0xff], 1).contains("cannot decode hex: not UTF-8 text"));
+    assert!(error(&["--format", "png"], b"GIF89a", 1).contains("cannot decode png: unknown file format"));
+    assert!(error(&["/nonexistent/icon.bin"], b"", 1).contains("cannot read /nonexistent/icon.bin"));
+}
+
+#[test]
+fn detect_format() {
+    let ascii = ["--style", "ascii"];
+
+    // Raw bytes that are not text.
+    assert_eq!(text(&["--style", "ascii", "--width", "8"], &[0x81, 0xff]), "#      #\n########\n");
+    // Text that is no hex is raw data, too.
+    assert_eq!(text(&["--style", "ascii", "--width", "8"], b"Hi"), " #  #   \n ## #  #\n");
+    // Hex text.
+    assert_eq!(text(&["--style", "ascii", "--width", "8", "--bytes", "0x81, 0xFF"], b""), "#      #\n########\n");
+    assert_eq!(text(&["--style", "ascii", "--width", "8"], b"00000000: 81ff  ..\n"), "#      #\n########\n");
+
+    assert_eq!(text(&ascii, b"P1\n4 2\n0110\n1001\n"), " ## \n#  #\n");
+    assert_eq!(text(&ascii, b"P4\n4 2\n\x60\x90"), " ## \n#  #\n");
+    let xbm = "#define icon_width 4\n#define icon_height 2\nstatic unsigned char icon_bits[] = { 0x06, 0x09 };\n";
+    assert_eq!(text(&ascii, xbm.as_bytes()), " ## \n#  #\n");
+
+    let bmp = [
+        b'B', b'M', 70, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0,
+        40, 0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 8, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 255, 255, 255, 0,
+        0b01100000, 0, 0, 0,
+        0b10010000, 0, 0, 0
+    ];
+    assert_eq!(text(&ascii, &bmp), " ## \n#  #\n");
+
+    let png = run(&["--width", "4", "--output", "png"], &[0b01101001]).stdout;
+    assert!(png.starts_with(b"\x89PNG"));
+    assert_eq!(text(&ascii, &png), " ## \n#  #\n");
+
+    // The format can be forced, e.g. to read text as raw bytes.
+    assert_eq!(text(&["--style", "ascii", "--width", "8", "--format", "raw"], b"P1"), " # #    \n  ##   #\n");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn full_output() {
+    let full = |args: &[&str], input: &[u8]| {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_bitmap_writer"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(std::fs::File::create("/dev/full").unwrap())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child.stdin.take().unwrap().write_all(input).unwrap();
+        let output = child.wait_with_output().unwrap();
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+        return String::from_utf8(output.stderr).unwrap();
+    };
+
+    for output in ["text", "png", "svg", "html", "rust", "c"] {
+        assert!(full(&["--width", "8", "--output", output], &[0x3c, 0x42]).contains("cannot write output"));
+    }
+    assert!(full(&["--scan", "--width", "8", "--height", "1"], &[0x3c, 0x42]).contains("cannot write output"));
+}